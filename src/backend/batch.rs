@@ -0,0 +1,169 @@
+use super::nutrients::{Intake, Kcal};
+use super::recipe::{Recipe, RecipeItem};
+use std::fmt;
+
+/// A dog in a batch, fed a recipe scaled to its recommended intake.
+pub struct BatchDog<'a> {
+    pub name: &'a str,
+    pub intake: &'a Intake,
+    pub recipe: &'a Recipe,
+}
+
+/// The food of one dog in a batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DogPortions {
+    pub name: String,
+    pub daily_kcal: Kcal,
+    /// The recipe scaled to the daily energy of the dog.
+    pub daily_recipe: Recipe,
+    pub containers: u32,
+    /// Grams in every container, except the last one when the days do not
+    /// divide evenly.
+    pub container_grams: f32,
+    pub last_container_grams: f32,
+}
+
+/// Everything to prepare for a batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Batch {
+    pub dogs: Vec<DogPortions>,
+    /// Total grams per ingredient over all dogs and days.
+    pub ingredients: Vec<RecipeItem>,
+}
+
+impl Batch {
+    pub fn total_grams(&self) -> f32 {
+        self.ingredients.iter().map(|item| item.grams).sum()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BatchError {
+    NoDays,
+    NoDaysPerContainer,
+    /// The recipe of the dog supplies no energy, so it cannot be scaled.
+    EmptyRecipe(String),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::NoDays => write!(f, "A batch needs at least one day"),
+            BatchError::NoDaysPerContainer => write!(f, "A container needs at least one day of food"),
+            BatchError::EmptyRecipe(name) => {
+                write!(f, "The recipe of {name} supplies no energy and cannot be scaled")
+            }
+        }
+    }
+}
+
+/// Scales the recipe of every dog to its daily energy and adds up the
+/// ingredients for the number of days. Every container holds the food of one
+/// dog for `days_per_container` days.
+pub fn plan_batch(dogs: &[BatchDog], days: u32, days_per_container: u32) -> Result<Batch, BatchError> {
+    if days == 0 {
+        return Err(BatchError::NoDays);
+    }
+    if days_per_container == 0 {
+        return Err(BatchError::NoDaysPerContainer);
+    }
+    let containers = days.div_ceil(days_per_container);
+    let last_container_days = days - (containers - 1) * days_per_container;
+
+    let mut ingredients: Vec<RecipeItem> = Vec::new();
+    let mut portions = Vec::new();
+    for dog in dogs {
+        let daily_recipe = dog
+            .recipe
+            .scaled_to(dog.intake.daily_kcal)
+            .ok_or_else(|| BatchError::EmptyRecipe(dog.name.to_string()))?;
+        for item in &daily_recipe.items {
+            let grams = item.grams * days as f32;
+            match ingredients.iter_mut().find(|total| total.ingredient == item.ingredient) {
+                Some(total) => total.grams += grams,
+                None => ingredients.push(RecipeItem {
+                    ingredient: item.ingredient.clone(),
+                    grams,
+                }),
+            }
+        }
+        let daily_grams = daily_recipe.total_grams();
+        portions.push(DogPortions {
+            name: dog.name.to_string(),
+            daily_kcal: dog.intake.daily_kcal,
+            containers,
+            container_grams: daily_grams * days_per_container as f32,
+            last_container_grams: daily_grams * last_container_days as f32,
+            daily_recipe,
+        });
+    }
+
+    Ok(Batch {
+        dogs: portions,
+        ingredients,
+    })
+}
+
+/// Energy in a full container of the dog.
+pub fn container_kcal(portions: &DogPortions, days_per_container: u32) -> Kcal {
+    portions.daily_recipe.intake().daily_kcal * days_per_container as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::{Amount, FromValue};
+
+    fn recipe(items: &[(&str, f32)]) -> Recipe {
+        Recipe {
+            items: items
+                .iter()
+                .map(|(ingredient, grams)| RecipeItem {
+                    ingredient: ingredient.to_string(),
+                    grams: *grams,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn batch_scales_recipes_to_every_dog() {
+        let mix = recipe(&[("Chicken necks", 300.0), ("Beef heart", 100.0)]);
+        let mix_kcal = mix.intake().daily_kcal.value();
+        let small = Intake {
+            daily_kcal: Kcal::from_value(mix_kcal / 2.0),
+            ..Default::default()
+        };
+        let large = Intake {
+            daily_kcal: Kcal::from_value(mix_kcal),
+            ..Default::default()
+        };
+        let dogs = [
+            BatchDog { name: "Small", intake: &small, recipe: &mix },
+            BatchDog { name: "Large", intake: &large, recipe: &mix },
+        ];
+        let batch = plan_batch(&dogs, 14, 4).unwrap();
+        let close = |actual: f32, expected: f32| (actual - expected).abs() <= expected * 1e-4;
+
+        // 1.5 times the recipe per day for 14 days
+        assert!(close(batch.ingredients[0].grams, 300.0 * 1.5 * 14.0));
+        assert!(close(batch.ingredients[1].grams, 100.0 * 1.5 * 14.0));
+        assert!(close(batch.total_grams(), 400.0 * 1.5 * 14.0));
+
+        let small = &batch.dogs[0];
+        assert_eq!(small.containers, 4);
+        assert!(close(small.container_grams, 200.0 * 4.0));
+        assert!(close(small.last_container_grams, 200.0 * 2.0));
+        assert!(close(container_kcal(small, 4).value(), mix_kcal / 2.0 * 4.0));
+    }
+
+    #[test]
+    fn invalid_batches() {
+        let intake = Intake::default();
+        let empty = Recipe::default();
+        let dogs = [BatchDog { name: "Rex", intake: &intake, recipe: &empty }];
+        assert_eq!(plan_batch(&dogs, 0, 1), Err(BatchError::NoDays));
+        assert_eq!(plan_batch(&dogs, 14, 0), Err(BatchError::NoDaysPerContainer));
+        assert_eq!(plan_batch(&dogs, 14, 1), Err(BatchError::EmptyRecipe("Rex".to_string())));
+    }
+}
@@ -0,0 +1,111 @@
+use super::nutrients::{FromValue, Kilogram};
+use super::super::shared::types::Age;
+use serde::Deserialize;
+use std::fmt;
+use std::sync::OnceLock;
+
+const BREEDS_TOML: &str = include_str!("data/breeds.toml");
+
+/// Weeks in a year of age, dogs are puppies during their first year.
+pub const WEEKS_PER_YEAR: u32 = 52;
+
+/// Size classes by typical adult weight. Larger dogs age faster, so the size
+/// class determines when a dog is considered senior.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeClass {
+    Toy,
+    Small,
+    /// Dogs of unknown or mixed breed are treated as medium sized.
+    #[default]
+    Medium,
+    Large,
+    Giant,
+}
+
+impl SizeClass {
+    /// Age in years from which a dog is considered senior, roughly the last
+    /// quarter of the life expectancy of its size class.
+    pub fn senior_age_years(&self) -> u32 {
+        match self {
+            SizeClass::Toy | SizeClass::Small => 10,
+            SizeClass::Medium => 9,
+            SizeClass::Large => 8,
+            SizeClass::Giant => 6,
+        }
+    }
+
+    /// The age group of a dog of this size class at the given age in weeks.
+    pub fn age(&self, age_weeks: u32) -> Age {
+        if age_weeks < WEEKS_PER_YEAR {
+            Age::Puppy
+        } else if age_weeks / WEEKS_PER_YEAR >= self.senior_age_years() {
+            Age::Senior
+        } else {
+            Age::Adult
+        }
+    }
+}
+
+impl fmt::Display for SizeClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A dog breed with its typical adult weight range in kg.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Breed {
+    pub name: String,
+    pub size_class: SizeClass,
+    pub min_weight: f32,
+    pub max_weight: f32,
+    /// Energy requirement of the breed relative to the activity level
+    /// coefficients, 1.0 for breeds without a known tendency.
+    #[serde(default = "default_energy_factor")]
+    pub energy_factor: f32,
+}
+
+fn default_energy_factor() -> f32 {
+    1.0
+}
+
+impl Breed {
+    /// The middle of the typical adult weight range.
+    pub fn expected_adult_weight(&self) -> Kilogram {
+        Kilogram::from_value((self.min_weight + self.max_weight) / 2.0)
+    }
+}
+
+#[derive(Deserialize)]
+struct Dataset {
+    breed: Vec<Breed>,
+}
+
+/// All breeds of the bundled dataset, parsed on first use.
+pub fn all() -> &'static [Breed] {
+    static BREEDS: OnceLock<Vec<Breed>> = OnceLock::new();
+    BREEDS.get_or_init(|| {
+        toml::from_str::<Dataset>(BREEDS_TOML)
+            .expect("bundled breed dataset should be valid")
+            .breed
+    })
+}
+
+/// Looks up a breed by its exact name, ignoring case.
+pub fn find(name: &str) -> Option<&'static Breed> {
+    all().iter().find(|breed| breed.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn larger_breeds_become_senior_earlier() {
+        let seven_years = 7 * WEEKS_PER_YEAR;
+        assert_eq!(SizeClass::Giant.age(seven_years), Age::Senior);
+        assert_eq!(SizeClass::Small.age(seven_years), Age::Adult);
+        assert_eq!(SizeClass::Giant.age(WEEKS_PER_YEAR - 1), Age::Puppy);
+    }
+}
@@ -0,0 +1,469 @@
+use super::ingredients::{self, Ingredient};
+use super::nutrients::{Amount, Intake, Nutrients};
+use super::recipe::{Recipe, RecipeItem};
+use std::fmt;
+use strum::EnumIter;
+
+/// Values smaller than this are treated as zero by the simplex method.
+const EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relation {
+    AtLeast,
+    AtMost,
+    Equal,
+}
+
+/// A linear constraint `coefficients * x (>=, <=, =) rhs`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    pub coefficients: Vec<f64>,
+    pub relation: Relation,
+    pub rhs: f64,
+}
+
+/// A linear program minimizing `objective * x` subject to the constraints and
+/// `x >= 0`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinearProgram {
+    pub objective: Vec<f64>,
+    pub constraints: Vec<Constraint>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SolveError {
+    Infeasible,
+    Unbounded,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Infeasible => write!(f, "No combination of the ingredients meets all constraints"),
+            SolveError::Unbounded => write!(f, "The objective can be decreased without limit"),
+        }
+    }
+}
+
+impl LinearProgram {
+    /// Solves the program with the two-phase simplex method, using Bland's rule
+    /// to prevent cycling. Returns the optimal value of every variable.
+    pub fn minimize(&self) -> Result<Vec<f64>, SolveError> {
+        let variables = self.objective.len();
+        let rows = self.constraints.len();
+
+        // Every row is normalized to a non-negative right hand side, flipping
+        // the relation where needed.
+        let normalized: Vec<(f64, Relation)> = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let sign = if constraint.rhs < 0.0 { -1.0 } else { 1.0 };
+                let relation = match (constraint.relation, sign < 0.0) {
+                    (Relation::AtLeast, true) => Relation::AtMost,
+                    (Relation::AtMost, true) => Relation::AtLeast,
+                    (relation, _) => relation,
+                };
+                (sign, relation)
+            })
+            .collect();
+        let slacks = normalized
+            .iter()
+            .filter(|(_, relation)| *relation != Relation::Equal)
+            .count();
+        let artificials = normalized
+            .iter()
+            .filter(|(_, relation)| *relation != Relation::AtMost)
+            .count();
+        let columns = variables + slacks + artificials;
+
+        let mut tableau = vec![vec![0.0; columns + 1]; rows];
+        let mut basis = vec![0; rows];
+        let mut slack = variables;
+        let mut artificial = variables + slacks;
+        for (row, (constraint, (sign, relation))) in self.constraints.iter().zip(normalized).enumerate() {
+            for (column, coefficient) in constraint.coefficients.iter().enumerate() {
+                tableau[row][column] = sign * coefficient;
+            }
+            tableau[row][columns] = sign * constraint.rhs;
+
+            match relation {
+                Relation::AtMost => {
+                    tableau[row][slack] = 1.0;
+                    basis[row] = slack;
+                    slack += 1;
+                }
+                Relation::AtLeast => {
+                    tableau[row][slack] = -1.0;
+                    tableau[row][artificial] = 1.0;
+                    basis[row] = artificial;
+                    slack += 1;
+                    artificial += 1;
+                }
+                Relation::Equal => {
+                    tableau[row][artificial] = 1.0;
+                    basis[row] = artificial;
+                    artificial += 1;
+                }
+            }
+        }
+
+        // Phase 1, find a feasible basis by minimizing the artificial variables.
+        let is_artificial = |column: usize| column >= variables + slacks;
+        let phase_one: Vec<f64> = (0..columns)
+            .map(|column| if is_artificial(column) { 1.0 } else { 0.0 })
+            .collect();
+        run_simplex(&mut tableau, &mut basis, &phase_one, columns)?;
+        let infeasibility: f64 = basis
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| is_artificial(**column))
+            .map(|(row, _)| tableau[row][columns])
+            .sum();
+        if infeasibility > EPSILON.sqrt() {
+            return Err(SolveError::Infeasible);
+        }
+
+        // Drive artificial variables that are still basic (at zero) out of the
+        // basis where possible, the remaining rows are redundant.
+        for row in 0..rows {
+            if !is_artificial(basis[row]) {
+                continue;
+            }
+            if let Some(column) =
+                (0..variables + slacks).find(|column| tableau[row][*column].abs() > EPSILON)
+            {
+                pivot(&mut tableau, &mut basis, row, column);
+            }
+        }
+
+        // Phase 2, optimize the actual objective without artificial variables.
+        let phase_two: Vec<f64> = (0..columns)
+            .map(|column| self.objective.get(column).copied().unwrap_or(0.0))
+            .collect();
+        run_simplex(&mut tableau, &mut basis, &phase_two, variables + slacks)?;
+
+        let mut solution = vec![0.0; variables];
+        for (row, column) in basis.iter().enumerate() {
+            if *column < variables {
+                solution[*column] = tableau[row][columns];
+            }
+        }
+        Ok(solution)
+    }
+}
+
+/// Pivots until no column below `allowed_columns` has a negative reduced cost.
+fn run_simplex(
+    tableau: &mut [Vec<f64>],
+    basis: &mut [usize],
+    cost: &[f64],
+    allowed_columns: usize,
+) -> Result<(), SolveError> {
+    let rhs = cost.len();
+    loop {
+        let entering = (0..allowed_columns).find(|column| {
+            let reduced_cost = cost[*column]
+                - basis
+                    .iter()
+                    .enumerate()
+                    .map(|(row, basic)| cost[*basic] * tableau[row][*column])
+                    .sum::<f64>();
+            reduced_cost < -EPSILON
+        });
+        let Some(entering) = entering else {
+            return Ok(());
+        };
+
+        let leaving = (0..tableau.len())
+            .filter(|row| tableau[*row][entering] > EPSILON)
+            .min_by(|a, b| {
+                let ratio_a = tableau[*a][rhs] / tableau[*a][entering];
+                let ratio_b = tableau[*b][rhs] / tableau[*b][entering];
+                ratio_a
+                    .total_cmp(&ratio_b)
+                    .then(basis[*a].cmp(&basis[*b]))
+            });
+        let Some(leaving) = leaving else {
+            return Err(SolveError::Unbounded);
+        };
+
+        pivot(tableau, basis, leaving, entering);
+    }
+}
+
+fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize], row: usize, column: usize) {
+    let divisor = tableau[row][column];
+    for value in tableau[row].iter_mut() {
+        *value /= divisor;
+    }
+    let pivot_row = tableau[row].clone();
+    for (other, values) in tableau.iter_mut().enumerate() {
+        let factor = values[column];
+        if other != row && factor != 0.0 {
+            for (value, pivot_value) in values.iter_mut().zip(&pivot_row) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    basis[row] = column;
+}
+
+/// What the recipe optimizer minimizes.
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum Objective {
+    Cost,
+    #[default]
+    Weight,
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An ingredient the optimizer may use, with its daily inclusion limits.
+#[derive(Clone, PartialEq)]
+pub struct IngredientOption {
+    /// Name of an ingredient in the bundled dataset.
+    pub ingredient: String,
+    pub min_grams: f32,
+    pub max_grams: Option<f32>,
+    /// Price per kg, only used when minimizing cost.
+    pub cost_per_kg: f32,
+}
+
+/// Every nutrient row of `Nutrients`, in the order of the results table.
+fn nutrient_values(nutrients: &Nutrients) -> Vec<f64> {
+    [&nutrients.protein as &dyn Amount, &nutrients.fat]
+        .into_iter()
+        .chain((&nutrients.amino_acids).into_iter().map(|(_, value)| value))
+        .chain((&nutrients.fatty_acids).into_iter().map(|(_, value)| value))
+        .chain((&nutrients.minerals).into_iter().map(|(_, value)| value))
+        .chain((&nutrients.vitamins).into_iter().map(|(_, value)| value))
+        .map(|value| value.value() as f64)
+        .collect()
+}
+
+/// Scales a row to a right hand side of one, which keeps IU and gram based rows
+/// in the same order of magnitude. Rows without a right hand side are not a
+/// constraint on non-negative amounts and are dropped.
+fn scaled_constraint(coefficients: Vec<f64>, relation: Relation, rhs: f64) -> Option<Constraint> {
+    if rhs > 0.0 {
+        Some(Constraint {
+            coefficients: coefficients.iter().map(|value| value / rhs).collect(),
+            relation,
+            rhs: 1.0,
+        })
+    } else {
+        None
+    }
+}
+
+/// Computes the amount of every allowed ingredient so the recipe meets every
+/// nutrient requirement of the intake, stays below the optional upper limits
+/// and supplies exactly the daily energy requirement. Upper limits of zero are
+/// treated as no limit.
+pub fn optimize_recipe(
+    intake: &Intake,
+    options: &[IngredientOption],
+    upper_limits: Option<&Nutrients>,
+    objective: Objective,
+) -> Result<Recipe, SolveError> {
+    // Variables are the amounts of every ingredient in units of 100 g, which
+    // matches the nutrient profiles of the dataset.
+    let options: Vec<(&IngredientOption, &Ingredient)> = options
+        .iter()
+        .filter_map(|option| ingredients::find(&option.ingredient).map(|ingredient| (option, ingredient)))
+        .collect();
+    let profiles: Vec<Vec<f64>> = options
+        .iter()
+        .map(|(_, ingredient)| nutrient_values(&ingredient.nutrients))
+        .collect();
+
+    let mut constraints = Vec::new();
+    constraints.extend(scaled_constraint(
+        options.iter().map(|(_, ingredient)| ingredient.kcal.value() as f64).collect(),
+        Relation::Equal,
+        intake.daily_kcal.value() as f64,
+    ));
+    for (row, required) in nutrient_values(&intake.nutrients).into_iter().enumerate() {
+        let coefficients: Vec<f64> = profiles.iter().map(|profile| profile[row]).collect();
+        constraints.extend(scaled_constraint(coefficients, Relation::AtLeast, required));
+    }
+    if let Some(upper_limits) = upper_limits {
+        for (row, limit) in nutrient_values(upper_limits).into_iter().enumerate() {
+            let coefficients: Vec<f64> = profiles.iter().map(|profile| profile[row]).collect();
+            constraints.extend(scaled_constraint(coefficients, Relation::AtMost, limit));
+        }
+    }
+    for (index, (option, _)) in options.iter().enumerate() {
+        let mut coefficients = vec![0.0; options.len()];
+        coefficients[index] = 1.0;
+        constraints.extend(scaled_constraint(
+            coefficients.clone(),
+            Relation::AtLeast,
+            option.min_grams as f64 / 100.0,
+        ));
+        match option.max_grams {
+            Some(max_grams) if max_grams > 0.0 => constraints.extend(scaled_constraint(
+                coefficients,
+                Relation::AtMost,
+                max_grams as f64 / 100.0,
+            )),
+            // A maximum of zero excludes the ingredient.
+            Some(_) => constraints.push(Constraint {
+                coefficients,
+                relation: Relation::AtMost,
+                rhs: 0.0,
+            }),
+            None => {}
+        }
+    }
+
+    let program = LinearProgram {
+        objective: options
+            .iter()
+            .map(|(option, _)| match objective {
+                Objective::Cost => option.cost_per_kg as f64 / 10.0,
+                Objective::Weight => 1.0,
+            })
+            .collect(),
+        constraints,
+    };
+    let solution = program.minimize()?;
+    Ok(Recipe {
+        items: options
+            .iter()
+            .zip(solution)
+            .filter(|(_, amount)| *amount > EPSILON)
+            .map(|((option, _), amount)| RecipeItem {
+                ingredient: option.ingredient.clone(),
+                grams: (amount * 100.0) as f32,
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::{FromValue, Kcal};
+
+    fn constraint(coefficients: &[f64], relation: Relation, rhs: f64) -> Constraint {
+        Constraint {
+            coefficients: coefficients.to_vec(),
+            relation,
+            rhs,
+        }
+    }
+
+    fn assert_solution(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-6, "{actual:?} != {expected:?}");
+        }
+    }
+
+    fn option(ingredient: &str, min_grams: f32, max_grams: Option<f32>) -> IngredientOption {
+        IngredientOption {
+            ingredient: ingredient.to_string(),
+            min_grams,
+            max_grams,
+            cost_per_kg: 0.0,
+        }
+    }
+
+    #[test]
+    fn textbook_optimum() {
+        // Maximize 3x + 5y subject to x <= 4, 2y <= 12 and 3x + 2y <= 18
+        let program = LinearProgram {
+            objective: vec![-3.0, -5.0],
+            constraints: vec![
+                constraint(&[1.0, 0.0], Relation::AtMost, 4.0),
+                constraint(&[0.0, 2.0], Relation::AtMost, 12.0),
+                constraint(&[3.0, 2.0], Relation::AtMost, 18.0),
+            ],
+        };
+        assert_solution(&program.minimize().unwrap(), &[2.0, 6.0]);
+    }
+
+    #[test]
+    fn infeasible_and_unbounded_programs() {
+        let infeasible = LinearProgram {
+            objective: vec![1.0, 1.0],
+            constraints: vec![
+                constraint(&[1.0, 1.0], Relation::AtMost, 1.0),
+                constraint(&[1.0, 1.0], Relation::AtLeast, 2.0),
+            ],
+        };
+        assert_eq!(infeasible.minimize(), Err(SolveError::Infeasible));
+
+        let unbounded = LinearProgram {
+            objective: vec![-1.0, 0.0],
+            constraints: vec![constraint(&[1.0, -1.0], Relation::AtLeast, 1.0)],
+        };
+        assert_eq!(unbounded.minimize(), Err(SolveError::Unbounded));
+    }
+
+    #[test]
+    fn negative_right_hand_sides_and_equalities() {
+        // x + y >= 4 written as -x - y <= -4, and y = x - 2 written as y - x = -2
+        let program = LinearProgram {
+            objective: vec![1.0, 2.0],
+            constraints: vec![
+                constraint(&[-1.0, -1.0], Relation::AtMost, -4.0),
+                constraint(&[-1.0, 1.0], Relation::Equal, -2.0),
+            ],
+        };
+        assert_solution(&program.minimize().unwrap(), &[3.0, 1.0]);
+    }
+
+    #[test]
+    fn redundant_equality_keeps_an_artificial_variable_basic() {
+        // The second row repeats the first, its artificial variable cannot be
+        // driven out of the basis after phase 1.
+        let program = LinearProgram {
+            objective: vec![1.0, 2.0],
+            constraints: vec![
+                constraint(&[1.0, 1.0], Relation::Equal, 2.0),
+                constraint(&[2.0, 2.0], Relation::Equal, 4.0),
+            ],
+        };
+        assert_solution(&program.minimize().unwrap(), &[2.0, 0.0]);
+    }
+
+    #[test]
+    fn recipes_respect_inclusion_limits_and_energy() {
+        let intake = Intake {
+            daily_kcal: Kcal::from_value(1000.0),
+            ..Default::default()
+        };
+        // Chicken necks supply 247 kcal and beef heart 112 kcal per 100 g, the
+        // lightest recipe uses the maximum of necks and makes up the rest with heart.
+        let options = [
+            option("Chicken necks", 0.0, Some(300.0)),
+            option("Beef heart", 150.0, None),
+        ];
+        let recipe = optimize_recipe(&intake, &options, None, Objective::Weight).unwrap();
+        let grams: Vec<f64> = recipe.items.iter().map(|item| item.grams as f64).collect();
+        assert!((grams[0] - 300.0).abs() < 0.1, "{grams:?}");
+        assert!((grams[1] - (1000.0 - 741.0) / 1.12).abs() < 0.1, "{grams:?}");
+        assert!((recipe.intake().daily_kcal.value() - 1000.0).abs() < 0.5);
+
+        let too_little = [
+            option("Chicken necks", 0.0, Some(100.0)),
+            option("Beef heart", 0.0, Some(100.0)),
+        ];
+        assert_eq!(
+            optimize_recipe(&intake, &too_little, None, Objective::Weight),
+            Err(SolveError::Infeasible)
+        );
+        let too_much = [option("Chicken necks", 500.0, None), option("Beef heart", 0.0, None)];
+        assert_eq!(
+            optimize_recipe(&intake, &too_much, None, Objective::Weight),
+            Err(SolveError::Infeasible)
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use super::nutrients::{Amount, Intake};
+use super::profiles::{date_from_days, days_since_epoch};
+use super::recipe::Recipe;
+use serde::{Deserialize, Serialize};
+
+/// Minerals of which the cumulative surplus or deficit is tracked, named as
+/// in the results table.
+pub const KEY_MINERALS: [&str; 5] = ["Calcium", "Phosphorus", "Zinc", "Copper", "Iron"];
+
+/// What a dog actually ate on a day.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiaryDay {
+    /// ISO 8601 date (YYYY-MM-DD).
+    pub date: String,
+    pub recipe: Recipe,
+}
+
+/// A diary day compared against the daily target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayBalance {
+    pub date: String,
+    pub supplied: Intake,
+    /// Supplied minus the target, negative for a deficit.
+    pub difference: Intake,
+    /// The differences of all days up to and including this one.
+    pub cumulative: Intake,
+}
+
+impl DayBalance {
+    /// The cumulative surplus or deficit of the key minerals.
+    pub fn key_minerals(&self) -> Vec<(&'static str, &dyn Amount)> {
+        (&self.cumulative.nutrients)
+            .into_iter()
+            .filter(|(name, _)| KEY_MINERALS.contains(name))
+            .collect()
+    }
+}
+
+/// The logged days of a week, starting on Monday, compared against the
+/// target for the same number of days.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeekBalance {
+    /// ISO 8601 date of the Monday.
+    pub week_start: String,
+    pub days: u32,
+    pub supplied: Intake,
+    pub target: Intake,
+}
+
+/// Compares every diary day against the daily target, days are expected in
+/// date order.
+pub fn day_balances(diary: &[DiaryDay], target: &Intake) -> Vec<DayBalance> {
+    let mut cumulative = Intake::default();
+    diary
+        .iter()
+        .map(|day| {
+            let supplied = day.recipe.intake();
+            let difference = supplied.clone() + target.clone() * -1.0;
+            cumulative = cumulative.clone() + difference.clone();
+            DayBalance {
+                date: day.date.clone(),
+                supplied,
+                difference,
+                cumulative: cumulative.clone(),
+            }
+        })
+        .collect()
+}
+
+/// The week of the date, with only the days that were logged. `None` when
+/// the date is not valid.
+pub fn week_balance(diary: &[DiaryDay], target: &Intake, date: &str) -> Option<WeekBalance> {
+    // 1970-01-01 was a Thursday
+    let day = days_since_epoch(date)?;
+    let monday = day - (day + 3).rem_euclid(7);
+    let week: Vec<&DiaryDay> = diary
+        .iter()
+        .filter(|logged| days_since_epoch(&logged.date).is_some_and(|logged| (monday..monday + 7).contains(&logged)))
+        .collect();
+    Some(WeekBalance {
+        week_start: date_from_days(monday),
+        days: week.len() as u32,
+        supplied: week.iter().map(|logged| logged.recipe.intake()).sum(),
+        target: target.clone() * week.len() as f32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::recipe::RecipeItem;
+
+    fn day(date: &str, grams: f32) -> DiaryDay {
+        DiaryDay {
+            date: date.to_string(),
+            recipe: Recipe {
+                items: vec![RecipeItem {
+                    ingredient: "Chicken necks".to_string(),
+                    grams,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn surplus_and_deficit_accumulate() {
+        let target = day("", 500.0).recipe.intake();
+        let diary = [day("2024-06-03", 600.0), day("2024-06-04", 300.0)];
+        let balances = day_balances(&diary, &target);
+        let kcal_per_gram = target.daily_kcal.value() / 500.0;
+        let close = |actual: f32, expected: f32| (actual - expected).abs() < 0.01 * expected.abs();
+        assert!(close(balances[0].difference.daily_kcal.value(), 100.0 * kcal_per_gram));
+        assert!(close(balances[1].cumulative.daily_kcal.value(), -100.0 * kcal_per_gram));
+        assert_eq!(balances[1].key_minerals().len(), KEY_MINERALS.len());
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let target = day("", 500.0).recipe.intake();
+        let diary = [day("2024-06-02", 500.0), day("2024-06-03", 500.0), day("2024-06-09", 500.0)];
+        // 2024-06-05 is a Wednesday
+        let week = week_balance(&diary, &target, "2024-06-05").unwrap();
+        assert_eq!(week.week_start, "2024-06-03");
+        assert_eq!(week.days, 2);
+        assert_eq!(week.target, target.clone() * 2.0);
+    }
+}
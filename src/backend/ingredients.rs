@@ -0,0 +1,71 @@
+use super::nutrients::{Kcal, Nutrients};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+const INGREDIENTS_TOML: &str = include_str!("data/ingredients.toml");
+
+/// A raw feeding ingredient with its nutrient profile per 100 g. Nutrients
+/// that were not measured for the ingredient are zero, the same as nutrients
+/// measured as zero, so supplied amounts of rarely reported nutrients such as
+/// iodine are a lower bound.
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct Ingredient {
+    pub name: String,
+    pub kcal: Kcal,
+    pub nutrients: Nutrients,
+}
+
+#[derive(Deserialize)]
+struct Dataset {
+    ingredient: Vec<Ingredient>,
+}
+
+/// All ingredients of the bundled dataset, parsed on first use.
+pub fn all() -> &'static [Ingredient] {
+    static INGREDIENTS: OnceLock<Vec<Ingredient>> = OnceLock::new();
+    INGREDIENTS.get_or_init(|| {
+        toml::from_str::<Dataset>(INGREDIENTS_TOML)
+            .expect("bundled ingredient dataset should be valid")
+            .ingredient
+    })
+}
+
+/// Looks up an ingredient by its exact name, ignoring case.
+pub fn find(name: &str) -> Option<&'static Ingredient> {
+    all()
+        .iter()
+        .find(|ingredient| ingredient.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns all ingredients whose name contains the query, ignoring case.
+pub fn search(query: &str) -> Vec<&'static Ingredient> {
+    let query = query.trim().to_lowercase();
+    all()
+        .iter()
+        .filter(|ingredient| ingredient.name.to_lowercase().contains(&query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::Amount;
+    use std::collections::HashSet;
+
+    #[test]
+    fn bundled_dataset_parses() {
+        let dataset = toml::from_str::<Dataset>(INGREDIENTS_TOML).unwrap();
+        assert!(!dataset.ingredient.is_empty());
+        let mut names = HashSet::new();
+        for (index, ingredient) in dataset.ingredient.iter().enumerate() {
+            let name = &ingredient.name;
+            assert!(!name.trim().is_empty(), "ingredient {index} has no name");
+            assert!(names.insert(name.to_lowercase()), "{name} is listed twice");
+            assert!(ingredient.kcal.value() >= 0.0, "{name}");
+            assert!(
+                (&ingredient.nutrients).into_iter().any(|(_, amount)| amount.value() > 0.0),
+                "{name} has no nutrients"
+            );
+        }
+    }
+}
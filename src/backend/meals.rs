@@ -0,0 +1,103 @@
+use super::super::shared::types::Age;
+use std::fmt;
+
+/// Puppies are fed four meals a day, grown dogs two.
+const PUPPY_MEALS_PER_DAY: u32 = 4;
+const ADULT_MEALS_PER_DAY: u32 = 2;
+
+/// More meals than this are not a realistic feeding schedule.
+pub const MAX_MEALS_PER_DAY: u32 = 8;
+
+/// The usual number of meals per day at an age.
+pub fn default_meals(age: Age) -> u32 {
+    match age {
+        Age::Puppy => PUPPY_MEALS_PER_DAY,
+        Age::Adult | Age::Senior => ADULT_MEALS_PER_DAY,
+    }
+}
+
+/// How the daily amounts are split over the meals of a day, as the fraction of
+/// the day of every meal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MealSplit {
+    fractions: Vec<f32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MealSplitError {
+    InvalidMeals,
+    InvalidShare(String),
+    /// The number of shares differs from the number of meals.
+    ShareCount { shares: usize, meals: u32 },
+}
+
+impl fmt::Display for MealSplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MealSplitError::InvalidMeals => {
+                write!(f, "Meals per day must be between 1 and {MAX_MEALS_PER_DAY}")
+            }
+            MealSplitError::InvalidShare(share) => {
+                write!(f, "\"{share}\" is not a valid share, use positive numbers such as 40/60")
+            }
+            MealSplitError::ShareCount { shares, meals } => {
+                write!(f, "The split has {shares} shares for {meals} meals")
+            }
+        }
+    }
+}
+
+impl MealSplit {
+    /// Splits the day over the meals, equally when no shares are given. Shares
+    /// are relative amounts separated by slashes, e.g. 40/60 or 1/2.
+    pub fn new(meals: u32, shares: &str) -> Result<Self, MealSplitError> {
+        if !(1..=MAX_MEALS_PER_DAY).contains(&meals) {
+            return Err(MealSplitError::InvalidMeals);
+        }
+        if shares.trim().is_empty() {
+            return Ok(MealSplit {
+                fractions: vec![1.0 / meals as f32; meals as usize],
+            });
+        }
+        let shares = shares
+            .split('/')
+            .map(|share| match share.trim().parse::<f32>() {
+                Ok(value) if value > 0.0 && value.is_finite() => Ok(value),
+                _ => Err(MealSplitError::InvalidShare(share.trim().to_string())),
+            })
+            .collect::<Result<Vec<f32>, _>>()?;
+        if shares.len() != meals as usize {
+            return Err(MealSplitError::ShareCount {
+                shares: shares.len(),
+                meals,
+            });
+        }
+        let total: f32 = shares.iter().sum();
+        Ok(MealSplit {
+            fractions: shares.iter().map(|share| share / total).collect(),
+        })
+    }
+
+    /// The fraction of the day of every meal, adding up to 1.
+    pub fn fractions(&self) -> &[f32] {
+        &self.fractions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_add_up_to_one_day() {
+        assert_eq!(MealSplit::new(4, "").unwrap().fractions(), &[0.25; 4]);
+        assert_eq!(MealSplit::new(2, "40/60").unwrap().fractions(), &[0.4, 0.6]);
+        assert_eq!(MealSplit::new(2, " 1 / 3 ").unwrap().fractions(), &[0.25, 0.75]);
+        assert_eq!(MealSplit::new(0, ""), Err(MealSplitError::InvalidMeals));
+        assert_eq!(
+            MealSplit::new(3, "40/60"),
+            Err(MealSplitError::ShareCount { shares: 2, meals: 3 })
+        );
+        assert_eq!(MealSplit::new(2, "40/-60"), Err(MealSplitError::InvalidShare("-60".to_string())));
+    }
+}
@@ -1,2 +1,13 @@
+pub mod batch;
+pub mod breeds;
 pub mod calculator;
+pub mod diary;
+pub mod ingredients;
+pub mod meals;
 pub mod nutrients;
+pub mod plan;
+pub mod profiles;
+pub mod ratios;
+pub mod recipe;
+pub mod requirements;
+pub mod storage;
@@ -1,64 +1,290 @@
-use super::super::shared::types::ActivityLevel;
+use super::super::shared::types::{
+    ActivityLevel, Age, EnergyUnit, Inputs, NeuterStatus, NutrientBasis, ReproductiveStatus,
+    UnitSystem, Units,
+};
+use super::requirements;
+use serde::{Deserialize, Deserializer};
 use std::fmt;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+use uom::si::energy::{kilocalorie, kilojoule};
+use uom::si::f32::{Energy, Mass};
+use uom::si::mass::{gram, kilogram, microgram, milligram, ounce, pound};
+use uom::si::ratio::ratio;
 
+/// Growing puppies need roughly twice the maintenance energy of an adult dog
+/// of the same weight (NRC 2006, after reaching 50% of adult weight).
+const PUPPY_ENERGY_FACTOR: f32 = 2.0;
+
+/// Puppies with an expected adult weight from this size up are considered
+/// large breed, which have a stricter calcium requirement during late growth.
+const LARGE_BREED_ADULT_WEIGHT_KG: f32 = 25.0;
+
+/// FEDIAF splits growth into early growth (< 14 weeks) and late growth.
+const LATE_GROWTH_AGE_WEEKS: u32 = 14;
+
+/// The heaviest dogs on record weighed around 150 kg, anything above that is
+/// considered a typo.
+const MAX_BODY_WEIGHT_KG: f32 = 150.0;
+
+/// AAFCO nutrient profiles per kg of dry matter assume an energy density of
+/// 4000 kcal of metabolizable energy per kg.
+const DRY_MATTER_KCAL_PER_KG: f32 = 4000.0;
+
+/// A body condition score of 5 out of 9 is ideal, every point above or below
+/// it is about 10% of the ideal weight over or under it.
+const IDEAL_BODY_CONDITION_SCORE: u32 = 5;
+/// A score of 4 is lean but still ideal.
+const LEAN_IDEAL_BODY_CONDITION_SCORE: u32 = 4;
+const BODY_CONDITION_WEIGHT_FRACTION: f32 = 0.1;
+
+/// Neutering lowers the energy requirement of adult dogs by about 20%.
+const NEUTERED_ENERGY_FACTOR: f32 = 0.8;
+
+/// Senior dogs are less active and lose lean mass, which lowers their energy
+/// requirement by about 20% compared to young adults.
+const SENIOR_ENERGY_FACTOR: f32 = 0.8;
+
+/// Body weight raised to the power 0.75, which is not a mass and therefore
+/// kept as a plain value in kg^0.75.
 #[derive(Clone, Default)]
 pub struct MetabolicBodyWeight {
-    pub value: Kilogram,
+    pub value: f32,
 }
 
 impl MetabolicBodyWeight {
     pub fn new(body_weight: Kilogram) -> Self {
-        let metabolic_bw = body_weight.value.powf(0.75);
-        return MetabolicBodyWeight {
-            value: Kilogram {
-                value: metabolic_bw,
-            },
-        };
+        MetabolicBodyWeight {
+            value: body_weight.value.get::<kilogram>().powf(0.75),
+        }
     }
 
     fn mul_f32<T: FromValue>(&self, rhs: f32) -> T {
-        T::from_value(self.value.value * rhs)
+        T::from_value(self.value * rhs)
     }
 }
 
+/// Estimated ideal body weight from the current weight and body condition
+/// score.
+pub fn ideal_weight(inputs: &Inputs) -> Kilogram {
+    let score = inputs
+        .body_condition_score
+        .unwrap_or(IDEAL_BODY_CONDITION_SCORE)
+        .clamp(1, 9);
+    let excess = (score as f32 - IDEAL_BODY_CONDITION_SCORE as f32) * BODY_CONDITION_WEIGHT_FRACTION;
+    Kilogram::from_value(inputs.weight / (1.0 + excess))
+}
+
+/// The weights at which the dog would have an ideal body condition score,
+/// from lean to ideal.
+pub fn ideal_weight_band(inputs: &Inputs) -> (Kilogram, Kilogram) {
+    let ideal = ideal_weight(inputs);
+    let lean = (LEAN_IDEAL_BODY_CONDITION_SCORE as f32 - IDEAL_BODY_CONDITION_SCORE as f32)
+        * BODY_CONDITION_WEIGHT_FRACTION;
+    (Kilogram::from_value(ideal.value() * (1.0 + lean)), ideal)
+}
+
 pub trait NewRecommendedIntake<T> {
-    fn new_recommended_intake(
-        metabolic_bw: &MetabolicBodyWeight,
-        activity_level: ActivityLevel,
-    ) -> T;
+    fn new_recommended_intake(inputs: &Inputs) -> T;
 }
 
-trait NewRecommendedNutrientIntake<T> {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> T;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GrowthStage {
+    /// Puppies younger than 14 weeks.
+    Early,
+    /// Puppies from 14 weeks onwards.
+    Late { large_breed: bool },
 }
 
+impl GrowthStage {
+    pub fn new(inputs: &Inputs) -> Self {
+        match inputs.age_weeks {
+            Some(weeks) if weeks < LATE_GROWTH_AGE_WEEKS => GrowthStage::Early,
+            _ => GrowthStage::Late {
+                large_breed: inputs
+                    .expected_adult_weight
+                    .is_some_and(|weight| weight >= LARGE_BREED_ADULT_WEIGHT_KG),
+            },
+        }
+    }
+}
 
-#[derive(Clone, Default)]
+
+/// Implements `Add`, `Mul<f32>` and `Sum` field by field for a group of
+/// nutrients, so amounts of e.g. several ingredients can be scaled and summed.
+macro_rules! impl_nutrient_arithmetic {
+    ($group:ident { $($field:ident),+ $(,)? }) => {
+        impl Add for $group {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $group {
+                    $($field: self.$field + rhs.$field,)+
+                }
+            }
+        }
+
+        impl Mul<f32> for $group {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self {
+                $group {
+                    $($field: self.$field * rhs,)+
+                }
+            }
+        }
+
+        impl Sum for $group {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($group::default(), Add::add)
+            }
+        }
+    };
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Intake {
     pub daily_kcal: Kcal,
     pub nutrients: Nutrients,
 }
 
 impl NewRecommendedIntake<Self> for Intake {
-    fn new_recommended_intake(
+    /// Requirements are based on the ideal weight, so over- and underweight dogs
+    /// are fed towards their ideal weight rather than their current weight.
+    fn new_recommended_intake(inputs: &Inputs) -> Self {
+        let inputs = &Inputs {
+            weight: ideal_weight(inputs).get(UnitSystem::Metric),
+            ..*inputs
+        };
+        let metabolic_bw = MetabolicBodyWeight::new(Kilogram::from_value(inputs.weight));
+        let tables = requirements::tables(inputs.standard);
+        // FEDIAF maintenance energy in kcal per kg^0.75, ranges use their midpoint
+        let maintenance_factor = match inputs.activity_level {
+            ActivityLevel::Sedentary => 95.00,
+            ActivityLevel::Moderate => 110.00,
+            ActivityLevel::Active => 125.00,
+            // Working dogs, 150 - 175.
+            ActivityLevel::High => 162.50,
+            // Racing sled dogs in extreme cold, 860 - 1240.
+            ActivityLevel::Extreme => 1070.00,
+        } * inputs.breed_energy_factor.unwrap_or(1.0);
+        let neuter_factor = match inputs.neuter_status {
+            NeuterStatus::Intact => 1.0,
+            NeuterStatus::Neutered => NEUTERED_ENERGY_FACTOR,
+        };
+
+        match (inputs.age, inputs.reproductive_status) {
+            (Age::Puppy, _) => Intake::new_growth_intake(inputs, &metabolic_bw, maintenance_factor),
+            (_, ReproductiveStatus::Gestation | ReproductiveStatus::Lactation) => {
+                Intake::new_reproduction_intake(inputs, &metabolic_bw)
+            }
+            (Age::Adult, _) => Intake {
+                daily_kcal: metabolic_bw.mul_f32(maintenance_factor * neuter_factor),
+                nutrients: tables.adult(&metabolic_bw),
+            },
+            // Seniors eat less but still need the same amount of nutrients, so
+            // only the energy requirement is reduced.
+            (Age::Senior, _) => Intake {
+                daily_kcal: metabolic_bw.mul_f32(maintenance_factor * neuter_factor * SENIOR_ENERGY_FACTOR),
+                nutrients: tables.adult(&metabolic_bw),
+            },
+        }
+    }
+}
+
+impl_nutrient_arithmetic!(Intake {
+    daily_kcal,
+    nutrients,
+});
+
+impl Intake {
+    /// Factor converting the daily amounts of this intake to the basis. An
+    /// intake without energy has no density, its factor is zero.
+    pub fn basis_factor(&self, basis: NutrientBasis) -> f32 {
+        let per_kcal = |kcal: f32| {
+            if self.daily_kcal.value() > 0.0 {
+                kcal / self.daily_kcal.value()
+            } else {
+                0.0
+            }
+        };
+        match basis {
+            NutrientBasis::Daily => 1.0,
+            NutrientBasis::PerThousandKcal => per_kcal(1000.0),
+            NutrientBasis::PerKgDryMatter => per_kcal(DRY_MATTER_KCAL_PER_KG),
+        }
+    }
+
+    /// The intake expressed as daily amounts or as a nutrient density.
+    pub fn density(&self, basis: NutrientBasis) -> Intake {
+        self.clone() * self.basis_factor(basis)
+    }
+
+    /// Safe upper limits for the recommended intake, zero means no limit.
+    pub fn upper_limits(&self, inputs: &Inputs) -> Nutrients {
+        let growth = inputs.age == Age::Puppy
+            || inputs.reproductive_status != ReproductiveStatus::Maintenance;
+        requirements::tables(inputs.standard).upper_limits(&self.daily_kcal, growth)
+    }
+
+    /// Growth energy follows the NRC 2006 equation
+    /// `130 * BW^0.75 * 3.2 * (e^(-0.87p) - 0.1)`, where `p` is the current
+    /// body weight as a fraction of the expected adult weight. Without an
+    /// expected adult weight we fall back to a fixed multiple of maintenance.
+    fn new_growth_intake(
+        inputs: &Inputs,
         metabolic_bw: &MetabolicBodyWeight,
-        activity_level: ActivityLevel,
+        maintenance_factor: f32,
     ) -> Self {
-        return Intake {
-            daily_kcal: match activity_level {
-                ActivityLevel::Sedentary => metabolic_bw.mul_f32(95.00),
-                ActivityLevel::Moderate => metabolic_bw.mul_f32(110.00),
-                ActivityLevel::Active => metabolic_bw.mul_f32(125.00),
-                ActivityLevel::High => metabolic_bw.mul_f32(162.50),
-                ActivityLevel::Extreme => metabolic_bw.mul_f32(1070.00),
-            },
-            nutrients: Nutrients::new_recommended_nutrient_intake(metabolic_bw),
+        let daily_kcal: Kcal = match inputs.expected_adult_weight {
+            Some(adult_weight) if adult_weight > 0.0 => {
+                let p = (inputs.weight / adult_weight).min(1.0);
+                metabolic_bw.mul_f32(130.00 * 3.2 * ((-0.87 * p).exp() - 0.1))
+            }
+            _ => metabolic_bw.mul_f32(maintenance_factor * PUPPY_ENERGY_FACTOR),
         };
+
+        Intake {
+            nutrients: requirements::tables(inputs.standard).growth(&daily_kcal, GrowthStage::new(inputs)),
+            daily_kcal,
+        }
     }
-}
 
+    /// Energy for late gestation and lactation follows NRC 2006:
+    /// - gestation: `132 * BW^0.75 + 26 * BW`
+    /// - lactation: `145 * BW^0.75 + BW * (24n) * L` for up to 4 puppies, and
+    ///   `145 * BW^0.75 + BW * (96 + 12(n - 4)) * L` for larger litters, where
+    ///   `L` depends on the week of lactation.
+    fn new_reproduction_intake(inputs: &Inputs, metabolic_bw: &MetabolicBodyWeight) -> Self {
+        let daily_kcal = match inputs.reproductive_status {
+            ReproductiveStatus::Lactation => {
+                let litter_factor = match inputs.litter_size {
+                    0..=4 => 24.0 * inputs.litter_size as f32,
+                    n => 96.0 + 12.0 * (n - 4) as f32,
+                };
+                let week_factor = match inputs.lactation_week {
+                    0 | 1 => 0.75,
+                    2 => 0.95,
+                    3 => 1.1,
+                    _ => 1.2,
+                };
+                Kcal::from_value(
+                    metabolic_bw.value * 145.00 + inputs.weight * litter_factor * week_factor,
+                )
+            }
+            _ => Kcal::from_value(metabolic_bw.value * 132.00 + inputs.weight * 26.00),
+        };
 
-#[derive(Clone, Default)]
+        Intake {
+            nutrients: requirements::tables(inputs.standard).growth(&daily_kcal, GrowthStage::Early),
+            daily_kcal,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
 pub struct Nutrients {
     pub protein: Gram,
     pub fat: Gram,
@@ -68,21 +294,38 @@ pub struct Nutrients {
     pub vitamins: Vitamins,
 }
 
-impl NewRecommendedNutrientIntake<Self> for Nutrients {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> Self {
-        return Nutrients {
-            protein: metabolic_bw.mul_f32(4.95),
-            fat: metabolic_bw.mul_f32(1.51),
-            amino_acids: AminoAcids::new_recommended_nutrient_intake(metabolic_bw),
-            fatty_acids: FattyAcids::new_recommended_nutrient_intake(metabolic_bw),
-            minerals: Minerals::new_recommended_nutrient_intake(metabolic_bw),
-            vitamins: Vitamins::new_recommended_nutrient_intake(metabolic_bw),
-        };
+impl<'a> IntoIterator for &'a Nutrients {
+    type Item = (&'static str, &'a dyn Amount);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    /// Every nutrient of every section, in the order of the results table.
+    fn into_iter(self) -> Self::IntoIter {
+        vec![
+            ("Protein", &self.protein as &dyn Amount),
+            ("Fat", &self.fat as &dyn Amount),
+        ]
+        .into_iter()
+        .chain(&self.amino_acids)
+        .chain(&self.fatty_acids)
+        .chain(&self.minerals)
+        .chain(&self.vitamins)
+        .collect::<Vec<_>>()
+        .into_iter()
     }
 }
 
+impl_nutrient_arithmetic!(Nutrients {
+    protein,
+    fat,
+    amino_acids,
+    fatty_acids,
+    minerals,
+    vitamins,
+});
 
-#[derive(Clone, Default)]
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
 pub struct AminoAcids {
     pub arginine: Gram,
     pub histidine: Gram,
@@ -98,72 +341,101 @@ pub struct AminoAcids {
     pub valine: Gram,
 }
 
-impl NewRecommendedNutrientIntake<Self> for AminoAcids {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> Self {
-        return AminoAcids {
-            arginine: metabolic_bw.mul_f32(0.14),
-            histidine: metabolic_bw.mul_f32(0.06),
-            isoleucine: metabolic_bw.mul_f32(0.13),
-            leucine: metabolic_bw.mul_f32(0.23),
-            lysine: metabolic_bw.mul_f32(0.12),
-            methionine: metabolic_bw.mul_f32(0.11),
-            cystine: metabolic_bw.mul_f32(0.10),
-            phenylalanine: metabolic_bw.mul_f32(0.15),
-            tyrosine: metabolic_bw.mul_f32(0.09),
-            threonine: metabolic_bw.mul_f32(0.14),
-            tryptophan: metabolic_bw.mul_f32(0.05),
-            valine: metabolic_bw.mul_f32(0.16),
-        };
+impl AminoAcids {
+    /// Cystine can be made from methionine, so the two are also required as a
+    /// combined amount.
+    pub fn methionine_cystine(&self) -> Gram {
+        self.methionine + self.cystine
+    }
+
+    /// Tyrosine can be made from phenylalanine, so the two are also required as
+    /// a combined amount.
+    pub fn phenylalanine_tyrosine(&self) -> Gram {
+        self.phenylalanine + self.tyrosine
     }
 }
 
 impl<'a> IntoIterator for &'a AminoAcids {
-    type Item = (&'static str, &'a dyn fmt::Display);
+    type Item = (&'static str, &'a dyn Amount);
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         vec![
-            ("Arginine", &self.arginine as &dyn fmt::Display),
-            ("Histidine", &self.histidine as &dyn fmt::Display),
-            ("Isoleucine", &self.isoleucine as &dyn fmt::Display),
-            ("Leucine", &self.leucine as &dyn fmt::Display),
-            ("Lysine", &self.lysine as &dyn fmt::Display),
-            ("Methionine", &self.methionine as &dyn fmt::Display),
-            ("Cystine", &self.cystine as &dyn fmt::Display),
-            ("Phenylalanine", &self.phenylalanine as &dyn fmt::Display),
-            ("Tyrosine", &self.tyrosine as &dyn fmt::Display),
-            ("Threonine", &self.threonine as &dyn fmt::Display),
-            ("Tryptophan", &self.tryptophan as &dyn fmt::Display),
-            ("Valine", &self.valine as &dyn fmt::Display),
+            ("Arginine", &self.arginine as &dyn Amount),
+            ("Histidine", &self.histidine as &dyn Amount),
+            ("Isoleucine", &self.isoleucine as &dyn Amount),
+            ("Leucine", &self.leucine as &dyn Amount),
+            ("Lysine", &self.lysine as &dyn Amount),
+            ("Methionine", &self.methionine as &dyn Amount),
+            ("Cystine", &self.cystine as &dyn Amount),
+            ("Phenylalanine", &self.phenylalanine as &dyn Amount),
+            ("Tyrosine", &self.tyrosine as &dyn Amount),
+            ("Threonine", &self.threonine as &dyn Amount),
+            ("Tryptophan", &self.tryptophan as &dyn Amount),
+            ("Valine", &self.valine as &dyn Amount),
         ].into_iter()
     }
 }
 
-#[derive(Clone, Default)]
+impl_nutrient_arithmetic!(AminoAcids {
+    arginine,
+    histidine,
+    isoleucine,
+    leucine,
+    lysine,
+    methionine,
+    cystine,
+    phenylalanine,
+    tyrosine,
+    threonine,
+    tryptophan,
+    valine,
+});
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
 pub struct FattyAcids {
     pub linoleic_acid: Gram,
+    pub alpha_linolenic_acid: Gram,
+    /// Eicosapentaenoic and docosahexaenoic acid, the omega-3 fatty acids of
+    /// fish oil, are required as a combined amount.
+    pub epa_dha: Gram,
+    pub arachidonic_acid: Gram,
 }
 
-impl NewRecommendedNutrientIntake<Self> for FattyAcids {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> FattyAcids {
-        return FattyAcids {
-            linoleic_acid: metabolic_bw.mul_f32(0.36),
-        };
+impl FattyAcids {
+    pub fn omega_6(&self) -> Gram {
+        self.linoleic_acid + self.arachidonic_acid
+    }
+
+    pub fn omega_3(&self) -> Gram {
+        self.alpha_linolenic_acid + self.epa_dha
     }
 }
 
 impl<'a> IntoIterator for &'a FattyAcids {
-    type Item = (&'static str, &'a dyn fmt::Display);
+    type Item = (&'static str, &'a dyn Amount);
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         vec![
-            ("Linoleic Acid", &self.linoleic_acid as &dyn fmt::Display),
+            ("Linoleic Acid", &self.linoleic_acid as &dyn Amount),
+            ("Alpha-Linolenic Acid", &self.alpha_linolenic_acid as &dyn Amount),
+            ("EPA + DHA", &self.epa_dha as &dyn Amount),
+            ("Arachidonic Acid", &self.arachidonic_acid as &dyn Amount),
         ].into_iter()
     }
 }
 
-#[derive(Clone, Default)]
+impl_nutrient_arithmetic!(FattyAcids {
+    linoleic_acid,
+    alpha_linolenic_acid,
+    epa_dha,
+    arachidonic_acid,
+});
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
 pub struct Minerals {
     pub calcium: Gram,
     pub phosphorus: Gram,
@@ -179,52 +451,49 @@ pub struct Minerals {
     pub zinc: Milligram,
 }
 
-impl NewRecommendedNutrientIntake<Self> for Minerals {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> Self {
-        return Minerals {
-            calcium: metabolic_bw.mul_f32(0.14),
-            phosphorus: metabolic_bw.mul_f32(0.11),
-            potassium: metabolic_bw.mul_f32(0.14),
-            sodium: metabolic_bw.mul_f32(0.03),
-            chloride: metabolic_bw.mul_f32(0.04),
-            magnesium: metabolic_bw.mul_f32(0.02),
-            copper: metabolic_bw.mul_f32(0.20),
-            iodine: metabolic_bw.mul_f32(0.03),
-            iron: metabolic_bw.mul_f32(1.00),
-            manganese: metabolic_bw.mul_f32(0.16),
-            selenium: metabolic_bw.mul_f32(6.40),
-            zinc: metabolic_bw.mul_f32(2.00),
-        };
-    }
-}
-
 impl<'a> IntoIterator for &'a Minerals {
-    type Item = (&'static str, &'a dyn fmt::Display);
+    type Item = (&'static str, &'a dyn Amount);
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         vec![
-            ("Calcium", &self.calcium as &dyn fmt::Display),
-            ("Phosphorus", &self.phosphorus as &dyn fmt::Display),
-            ("Potassium", &self.potassium as &dyn fmt::Display),
-            ("Sodium", &self.sodium as &dyn fmt::Display),
-            ("Chloride", &self.chloride as &dyn fmt::Display),
-            ("Magnesium", &self.magnesium as &dyn fmt::Display),
-            ("Copper", &self.copper as &dyn fmt::Display),
-            ("Iodine", &self.iodine as &dyn fmt::Display),
-            ("Iron", &self.iron as &dyn fmt::Display),
-            ("Manganese", &self.manganese as &dyn fmt::Display),
-            ("Selenium", &self.selenium as &dyn fmt::Display),
-            ("Zinc", &self.zinc as &dyn fmt::Display),
+            ("Calcium", &self.calcium as &dyn Amount),
+            ("Phosphorus", &self.phosphorus as &dyn Amount),
+            ("Potassium", &self.potassium as &dyn Amount),
+            ("Sodium", &self.sodium as &dyn Amount),
+            ("Chloride", &self.chloride as &dyn Amount),
+            ("Magnesium", &self.magnesium as &dyn Amount),
+            ("Copper", &self.copper as &dyn Amount),
+            ("Iodine", &self.iodine as &dyn Amount),
+            ("Iron", &self.iron as &dyn Amount),
+            ("Manganese", &self.manganese as &dyn Amount),
+            ("Selenium", &self.selenium as &dyn Amount),
+            ("Zinc", &self.zinc as &dyn Amount),
         ].into_iter()
     }
 }
 
-#[derive(Clone, Default)]
+impl_nutrient_arithmetic!(Minerals {
+    calcium,
+    phosphorus,
+    potassium,
+    sodium,
+    chloride,
+    magnesium,
+    copper,
+    iodine,
+    iron,
+    manganese,
+    selenium,
+    zinc,
+});
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
 pub struct Vitamins {
-    pub vit_a: IU,
-    pub vit_d: IU,
-    pub vit_e: IU,
+    pub vit_a: IU<VitaminA>,
+    pub vit_d: IU<VitaminD>,
+    pub vit_e: IU<VitaminE>,
     pub vit_b1: Milligram,
     pub vit_b2: Milligram,
     pub vit_b5: Milligram,
@@ -235,141 +504,756 @@ pub struct Vitamins {
     pub choline: Milligram,
 }
 
-impl NewRecommendedNutrientIntake<Self> for Vitamins {
-    fn new_recommended_nutrient_intake(metabolic_bw: &MetabolicBodyWeight) -> Self {
-        return Vitamins {
-            vit_a: metabolic_bw.mul_f32(167.00),
-            vit_d: metabolic_bw.mul_f32(15.20),
-            vit_e: metabolic_bw.mul_f32(1.00),
-            vit_b1: metabolic_bw.mul_f32(0.06),
-            vit_b2: metabolic_bw.mul_f32(0.17),
-            vit_b5: metabolic_bw.mul_f32(0.39),
-            vit_b6: metabolic_bw.mul_f32(0.04),
-            vit_b12: metabolic_bw.mul_f32(0.92),
-            vit_b3: metabolic_bw.mul_f32(0.45),
-            vit_b9: metabolic_bw.mul_f32(7.10),
-            choline: metabolic_bw.mul_f32(45.00),
-        };
-    }
-}
-
 impl<'a> IntoIterator for &'a Vitamins {
-    type Item = (&'static str, &'a dyn fmt::Display);
+    type Item = (&'static str, &'a dyn Amount);
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         vec![
-            ("Vitamin A", &self.vit_a as &dyn fmt::Display),
-            ("Vitamin D", &self.vit_d as &dyn fmt::Display),
-            ("Vitamin E", &self.vit_e as &dyn fmt::Display),
-            ("Vitamin B1", &self.vit_b1 as &dyn fmt::Display),
-            ("Vitamin B2", &self.vit_b2 as &dyn fmt::Display),
-            ("Vitamin B5", &self.vit_b5 as &dyn fmt::Display),
-            ("Vitamin B6", &self.vit_b6 as &dyn fmt::Display),
-            ("Vitamin B12", &self.vit_b12 as &dyn fmt::Display),
-            ("Vitamin B3", &self.vit_b3 as &dyn fmt::Display),
-            ("Vitamin B9", &self.vit_b9 as &dyn fmt::Display),
-            ("Choline", &self.choline as &dyn fmt::Display),
+            ("Vitamin A", &self.vit_a as &dyn Amount),
+            ("Vitamin D", &self.vit_d as &dyn Amount),
+            ("Vitamin E", &self.vit_e as &dyn Amount),
+            ("Vitamin B1", &self.vit_b1 as &dyn Amount),
+            ("Vitamin B2", &self.vit_b2 as &dyn Amount),
+            ("Vitamin B5", &self.vit_b5 as &dyn Amount),
+            ("Vitamin B6", &self.vit_b6 as &dyn Amount),
+            ("Vitamin B12", &self.vit_b12 as &dyn Amount),
+            ("Vitamin B3", &self.vit_b3 as &dyn Amount),
+            ("Vitamin B9", &self.vit_b9 as &dyn Amount),
+            ("Choline", &self.choline as &dyn Amount),
         ].into_iter()
     }
 }
 
+impl_nutrient_arithmetic!(Vitamins {
+    vit_a,
+    vit_d,
+    vit_e,
+    vit_b1,
+    vit_b2,
+    vit_b5,
+    vit_b6,
+    vit_b12,
+    vit_b3,
+    vit_b9,
+    choline,
+});
+
 pub trait FromValue {
     fn from_value(value: f32) -> Self;
 }
 
-#[derive(Clone, Default)]
-pub struct IU {
+/// A displayable amount of a nutrient, exposing its value in the unit it is
+/// displayed in so amounts of the same nutrient can be compared.
+pub trait Amount: fmt::Display {
+    fn value(&self) -> f32;
+
+    /// Formats the amount in the preferred units, amounts without an imperial
+    /// counterpart are shown as is.
+    fn display(&self, _units: Units) -> String {
+        self.to_string()
+    }
+}
+
+/// A vitamin measured in international units, which correspond to a
+/// different mass for every vitamin.
+pub trait IuVitamin: Clone + Copy + fmt::Debug + Default + PartialEq + PartialOrd {
+    const MICROGRAMS_PER_IU: f32;
+}
+
+/// Vitamin A as retinol.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct VitaminA;
+
+/// Vitamin D as cholecalciferol (D3).
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct VitaminD;
+
+/// Vitamin E as RRR-alpha-tocopherol.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct VitaminE;
+
+impl IuVitamin for VitaminA {
+    const MICROGRAMS_PER_IU: f32 = 0.3;
+}
+
+impl IuVitamin for VitaminD {
+    const MICROGRAMS_PER_IU: f32 = 0.025;
+}
+
+impl IuVitamin for VitaminE {
+    const MICROGRAMS_PER_IU: f32 = 670.0;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct IU<V: IuVitamin> {
     value: f32,
+    vitamin: PhantomData<V>,
 }
 
-#[derive(Clone, Default)]
+impl<V: IuVitamin> IU<V> {
+    /// The mass of the vitamin these international units correspond to.
+    pub fn mass(&self) -> Microgram {
+        Microgram::from_value(self.value * V::MICROGRAMS_PER_IU)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Kilogram {
-    value: f32,
+    value: Mass,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Gram {
-    value: f32,
+    value: Mass,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Microgram {
-    value: f32,
+    value: Mass,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Milligram {
-    value: f32,
+    value: Mass,
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
 pub struct Kcal {
-    value: f32,
+    value: Energy,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeightError {
+    NotANumber,
+    NotPositive,
+    TooHeavy,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::NotANumber => write!(f, "Weight must be a number"),
+            WeightError::NotPositive => write!(f, "Weight must be greater than zero"),
+            WeightError::TooHeavy => write!(f, "Weight can not exceed {MAX_BODY_WEIGHT_KG} kg"),
+        }
+    }
+}
+
+impl Kilogram {
+    /// Parses a body weight entered in the given unit system, rejecting values
+    /// that can not be the weight of a dog.
+    pub fn parse(input: &str, system: UnitSystem) -> Result<Self, WeightError> {
+        let value = input
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| WeightError::NotANumber)?;
+        if !value.is_finite() {
+            return Err(WeightError::NotANumber);
+        }
+        if value <= 0.0 {
+            return Err(WeightError::NotPositive);
+        }
+
+        let weight = Kilogram::new(value, system);
+        if weight.value.get::<kilogram>() > MAX_BODY_WEIGHT_KG {
+            return Err(WeightError::TooHeavy);
+        }
+        Ok(weight)
+    }
+
+    /// A weight entered in the given unit system, kg or lb.
+    pub fn new(value: f32, system: UnitSystem) -> Self {
+        match system {
+            UnitSystem::Metric => Kilogram::from_value(value),
+            UnitSystem::Imperial => Kilogram {
+                value: Mass::new::<pound>(value),
+            },
+        }
+    }
+
+    /// The weight in the given unit system, kg or lb.
+    pub fn get(&self, system: UnitSystem) -> f32 {
+        match system {
+            UnitSystem::Metric => self.value.get::<kilogram>(),
+            UnitSystem::Imperial => self.value.get::<pound>(),
+        }
+    }
 }
 
 impl fmt::Display for Kcal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} kcal", self.value.round())
+        write!(f, "{} kcal", self.value.get::<kilocalorie>().round())
+    }
+}
+
+impl fmt::Display for Kilogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} kg", self.value.get::<kilogram>())
     }
 }
 
 impl fmt::Display for Gram {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.2} gr", self.value)
+        write!(f, "{:.2} gr", self.value.get::<gram>())
     }
 }
 
 impl fmt::Display for Milligram {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.2} mg", self.value)
+        write!(f, "{:.2} mg", self.value.get::<milligram>())
     }
 }
 
 impl fmt::Display for Microgram {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:.2} µg", self.value)
+        write!(f, "{:.2} µg", self.value.get::<microgram>())
     }
 }
 
-impl fmt::Display for IU {
+impl<V: IuVitamin> fmt::Display for IU<V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} IU", self.value.round())
+        write!(f, "{} IU ({})", self.value.round(), self.mass())
     }
 }
 
 impl FromValue for Kcal {
     fn from_value(value: f32) -> Self {
-        Kcal { value }
+        Kcal {
+            value: Energy::new::<kilocalorie>(value),
+        }
     }
 }
 
-impl FromValue for IU {
+impl<V: IuVitamin> FromValue for IU<V> {
     fn from_value(value: f32) -> Self {
-        IU { value }
+        IU {
+            value,
+            vitamin: PhantomData,
+        }
     }
 }
 
 impl FromValue for Kilogram {
     fn from_value(value: f32) -> Self {
-        Kilogram { value }
+        Kilogram {
+            value: Mass::new::<kilogram>(value),
+        }
     }
 }
 
 impl FromValue for Gram {
     fn from_value(value: f32) -> Self {
-        Gram { value }
+        Gram {
+            value: Mass::new::<gram>(value),
+        }
     }
 }
 
 impl FromValue for Milligram {
     fn from_value(value: f32) -> Self {
-        Milligram { value }
+        Milligram {
+            value: Mass::new::<milligram>(value),
+        }
     }
 }
 
 impl FromValue for Microgram {
     fn from_value(value: f32) -> Self {
-        Microgram { value }
+        Microgram {
+            value: Mass::new::<microgram>(value),
+        }
+    }
+}
+
+impl Amount for Kcal {
+    fn value(&self) -> f32 {
+        self.value.get::<kilocalorie>()
+    }
+
+    fn display(&self, units: Units) -> String {
+        match units.energy {
+            EnergyUnit::Kcal => self.to_string(),
+            EnergyUnit::Kilojoule => format!("{} kJ", self.value.get::<kilojoule>().round()),
+        }
+    }
+}
+
+impl Amount for Kilogram {
+    fn value(&self) -> f32 {
+        self.value.get::<kilogram>()
+    }
+
+    fn display(&self, units: Units) -> String {
+        match units.system {
+            UnitSystem::Metric => self.to_string(),
+            UnitSystem::Imperial => format!("{:.1} lb", self.value.get::<pound>()),
+        }
+    }
+}
+
+impl<V: IuVitamin> Amount for IU<V> {
+    fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+impl Amount for Gram {
+    fn value(&self) -> f32 {
+        self.value.get::<gram>()
+    }
+
+    fn display(&self, units: Units) -> String {
+        match units.system {
+            UnitSystem::Metric => self.to_string(),
+            UnitSystem::Imperial => format!("{:.3} oz", self.value.get::<ounce>()),
+        }
+    }
+}
+
+impl Amount for Milligram {
+    fn value(&self) -> f32 {
+        self.value.get::<milligram>()
+    }
+}
+
+impl Amount for Microgram {
+    fn value(&self) -> f32 {
+        self.value.get::<microgram>()
+    }
+}
+
+/// Units are stored in the data files as plain numbers in the unit itself.
+macro_rules! impl_unit_deserialize {
+    ($unit:ty) => {
+        impl<'de> Deserialize<'de> for $unit {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                f32::deserialize(deserializer).map(FromValue::from_value)
+            }
+        }
+    };
+}
+
+impl_unit_deserialize!(Kcal);
+impl_unit_deserialize!(IU<VitaminA>);
+impl_unit_deserialize!(IU<VitaminD>);
+impl_unit_deserialize!(IU<VitaminE>);
+impl_unit_deserialize!(Kilogram);
+impl_unit_deserialize!(Gram);
+impl_unit_deserialize!(Milligram);
+impl_unit_deserialize!(Microgram);
+
+/// Implements `Add`, `Sub`, `Mul<f32>`, `Div` and `Sum` for a unit. Dividing
+/// two amounts of the same unit gives their ratio.
+macro_rules! impl_unit_arithmetic {
+    ($unit:ident) => {
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $unit { value: self.value + rhs.value }
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $unit { value: self.value - rhs.value }
+            }
+        }
+
+        impl Mul<f32> for $unit {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self {
+                $unit { value: self.value * rhs }
+            }
+        }
+
+        impl Div for $unit {
+            type Output = f32;
+
+            fn div(self, rhs: Self) -> f32 {
+                (self.value / rhs.value).get::<ratio>()
+            }
+        }
+
+        impl Sum for $unit {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($unit::default(), Add::add)
+            }
+        }
+    };
+}
+
+impl_unit_arithmetic!(Kcal);
+impl_unit_arithmetic!(Kilogram);
+impl_unit_arithmetic!(Gram);
+impl_unit_arithmetic!(Milligram);
+impl_unit_arithmetic!(Microgram);
+
+impl<V: IuVitamin> Add for IU<V> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        IU::from_value(self.value + rhs.value)
+    }
+}
+
+impl<V: IuVitamin> Sub for IU<V> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        IU::from_value(self.value - rhs.value)
+    }
+}
+
+impl<V: IuVitamin> Mul<f32> for IU<V> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        IU::from_value(self.value * rhs)
+    }
+}
+
+impl<V: IuVitamin> Div for IU<V> {
+    type Output = f32;
+
+    fn div(self, rhs: Self) -> f32 {
+        self.value / rhs.value
+    }
+}
+
+impl<V: IuVitamin> Sum for IU<V> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(IU::default(), Add::add)
+    }
+}
+
+/// All mass units share the same underlying quantity, so converting between
+/// them only changes the unit they are displayed in.
+macro_rules! impl_mass_conversion {
+    ($from:ident => $to:ident) => {
+        impl From<$from> for $to {
+            fn from(mass: $from) -> Self {
+                $to { value: mass.value }
+            }
+        }
+    };
+}
+
+impl_mass_conversion!(Kilogram => Gram);
+impl_mass_conversion!(Gram => Kilogram);
+impl_mass_conversion!(Gram => Milligram);
+impl_mass_conversion!(Milligram => Gram);
+impl_mass_conversion!(Milligram => Microgram);
+impl_mass_conversion!(Microgram => Milligram);
+impl_mass_conversion!(Gram => Microgram);
+impl_mass_conversion!(Microgram => Gram);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::types::GuidelineStandard;
+    use strum::IntoEnumIterator;
+
+    const WEIGHTS_KG: [f32; 6] = [2.0, 5.0, 10.0, 20.0, 40.0, 70.0];
+
+    fn adult(weight: f32, activity_level: ActivityLevel, standard: GuidelineStandard) -> Intake {
+        Intake::new_recommended_intake(&Inputs {
+            weight,
+            activity_level,
+            standard,
+            ..Default::default()
+        })
+    }
+
+    /// Published values are rounded, so a small relative difference is allowed.
+    fn assert_close(name: &str, actual: f32, expected: f32) {
+        let tolerance = expected.abs() * 0.03;
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{name}: expected {expected}, got {actual}"
+        );
+    }
+
+    /// Published FEDIAF maintenance energy per kg^0.75 of every activity level.
+    #[test]
+    fn energy_within_published_ranges() {
+        for weight in WEIGHTS_KG {
+            let metabolic_bw = MetabolicBodyWeight::new(Kilogram::from_value(weight));
+            for (activity_level, min, max) in [
+                (ActivityLevel::Sedentary, 95.0, 95.0),
+                (ActivityLevel::Moderate, 110.0, 110.0),
+                (ActivityLevel::Active, 125.0, 125.0),
+                (ActivityLevel::High, 150.0, 175.0),
+                (ActivityLevel::Extreme, 860.0, 1240.0),
+            ] {
+                let intake = adult(weight, activity_level, GuidelineStandard::Fediaf);
+                let per_metabolic_bw = intake.daily_kcal.value() / metabolic_bw.value;
+                assert!(
+                    (min - 0.01..=max + 0.01).contains(&per_metabolic_bw),
+                    "{activity_level} at {weight} kg: {per_metabolic_bw} kcal/kg^0.75"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn energy_golden_values() {
+        // 10 kg adult, 110 * 10^0.75
+        assert_close(
+            "adult",
+            adult(10.0, ActivityLevel::Moderate, GuidelineStandard::Fediaf).daily_kcal.value(),
+            618.6,
+        );
+
+        // 10 kg puppy at half its adult weight, 130 * 10^0.75 * 3.2 * (e^-0.435 - 0.1)
+        let puppy = Intake::new_recommended_intake(&Inputs {
+            age: Age::Puppy,
+            weight: 10.0,
+            age_weeks: Some(16),
+            expected_adult_weight: Some(20.0),
+            ..Default::default()
+        });
+        assert_close("puppy", puppy.daily_kcal.value(), 1280.2);
+
+        // 20 kg bitch in late gestation, 132 * 20^0.75 + 26 * 20
+        let gestation = Intake::new_recommended_intake(&Inputs {
+            weight: 20.0,
+            reproductive_status: ReproductiveStatus::Gestation,
+            ..Default::default()
+        });
+        assert_close("gestation", gestation.daily_kcal.value(), 1768.4);
+
+        // 20 kg bitch in week 4 of lactation with 6 puppies,
+        // 145 * 20^0.75 + 20 * (96 + 12 * 2) * 1.2
+        let lactation = Intake::new_recommended_intake(&Inputs {
+            weight: 20.0,
+            reproductive_status: ReproductiveStatus::Lactation,
+            litter_size: 6,
+            lactation_week: 4,
+            ..Default::default()
+        });
+        assert_close("lactation", lactation.daily_kcal.value(), 4251.3);
+    }
+
+    /// NRC 2006 recommended allowances per day of a 15 kg adult dog.
+    #[test]
+    fn nrc_adult_golden_values() {
+        let intake = adult(15.0, ActivityLevel::Moderate, GuidelineStandard::Nrc);
+        let nutrients = &intake.nutrients;
+        assert_close("protein", nutrients.protein.value(), 25.0);
+        assert_close("linoleic acid", nutrients.fatty_acids.linoleic_acid.value(), 2.8);
+        assert_close("calcium", nutrients.minerals.calcium.value(), 1.0);
+        assert_close("phosphorus", nutrients.minerals.phosphorus.value(), 0.75);
+        assert_close("zinc", nutrients.minerals.zinc.value(), 15.0);
+        assert_close("iron", nutrients.minerals.iron.value(), 7.5);
+        assert_close("vitamin A", nutrients.vitamins.vit_a.value(), 1263.0);
+        assert_close("choline", nutrients.vitamins.choline.value(), 425.0);
+    }
+
+    /// FEDIAF adult minimums per 1000 kcal, for dogs eating 110 kcal/kg^0.75.
+    #[test]
+    fn fediaf_adult_golden_values() {
+        let intake = adult(10.0, ActivityLevel::Moderate, GuidelineStandard::Fediaf)
+            .density(NutrientBasis::PerThousandKcal);
+        let nutrients = &intake.nutrients;
+        assert_close("protein", nutrients.protein.value(), 45.0);
+        assert_close("fat", nutrients.fat.value(), 13.75);
+        assert_close("calcium", nutrients.minerals.calcium.value(), 1.25);
+        assert_close("phosphorus", nutrients.minerals.phosphorus.value(), 1.0);
+        assert_close("zinc", nutrients.minerals.zinc.value(), 18.0);
+        assert_close("vitamin A", nutrients.vitamins.vit_a.value(), 1515.0);
+        assert_close("vitamin D", nutrients.vitamins.vit_d.value(), 138.0);
+    }
+
+    /// A nutrient missing from the requirement file silently becomes zero.
+    #[test]
+    fn every_standard_requires_every_core_nutrient() {
+        for standard in GuidelineStandard::iter() {
+            for age in Age::iter() {
+                let intake = Intake::new_recommended_intake(&Inputs {
+                    age,
+                    weight: 10.0,
+                    standard,
+                    age_weeks: Some(10),
+                    ..Default::default()
+                });
+                let nutrients = &intake.nutrients;
+                let core = (&nutrients.amino_acids)
+                    .into_iter()
+                    .chain(&nutrients.minerals)
+                    .chain(&nutrients.vitamins)
+                    .chain([
+                        ("Protein", &nutrients.protein as &dyn Amount),
+                        ("Fat", &nutrients.fat as &dyn Amount),
+                        ("Linoleic Acid", &nutrients.fatty_acids.linoleic_acid as &dyn Amount),
+                    ]);
+                for (name, value) in core {
+                    assert!(value.value() > 0.0, "{standard} {age}: {name} is not required");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn monotonic_in_weight() {
+        for standard in GuidelineStandard::iter() {
+            for activity_level in ActivityLevel::iter() {
+                for pair in WEIGHTS_KG.windows(2) {
+                    let lighter = adult(pair[0], activity_level, standard);
+                    let heavier = adult(pair[1], activity_level, standard);
+                    assert!(heavier.daily_kcal > lighter.daily_kcal);
+                    for ((name, light), (_, heavy)) in (&lighter.nutrients).into_iter().zip(&heavier.nutrients) {
+                        assert!(
+                            heavy.value() >= light.value(),
+                            "{standard} {activity_level}: {name} drops from {} kg to {} kg",
+                            pair[0],
+                            pair[1]
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn monotonic_in_activity() {
+        for weight in WEIGHTS_KG {
+            let energies: Vec<Kcal> = ActivityLevel::iter()
+                .map(|activity_level| adult(weight, activity_level, GuidelineStandard::Fediaf).daily_kcal)
+                .collect();
+            assert!(
+                energies.windows(2).all(|pair| pair[1] > pair[0]),
+                "energy at {weight} kg is not increasing with activity: {energies:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn energy_is_based_on_the_ideal_weight() {
+        let overweight = Intake::new_recommended_intake(&Inputs {
+            weight: 24.0,
+            body_condition_score: Some(7),
+            ..Default::default()
+        });
+        let ideal = adult(20.0, ActivityLevel::Moderate, GuidelineStandard::Fediaf);
+        assert_close("overweight", overweight.daily_kcal.value(), ideal.daily_kcal.value());
+        let (lean, ideal_weight) = ideal_weight_band(&Inputs {
+            weight: 24.0,
+            body_condition_score: Some(7),
+            ..Default::default()
+        });
+        assert_close("lean", lean.value(), 18.0);
+        assert_close("ideal", ideal_weight.value(), 20.0);
+
+        let neutered = Intake::new_recommended_intake(&Inputs {
+            weight: 20.0,
+            neuter_status: NeuterStatus::Neutered,
+            ..Default::default()
+        });
+        assert!(neutered.daily_kcal < ideal.daily_kcal);
+        assert_eq!(neutered.nutrients, ideal.nutrients);
+    }
+
+    #[test]
+    fn breed_energy_factor_scales_only_the_energy() {
+        let average = adult(20.0, ActivityLevel::Moderate, GuidelineStandard::Fediaf);
+        let breed = crate::backend::breeds::find("border collie").unwrap();
+        let border_collie = Intake::new_recommended_intake(&Inputs {
+            weight: 20.0,
+            breed_energy_factor: Some(breed.energy_factor),
+            ..Default::default()
+        });
+        assert_close("breed", border_collie.daily_kcal.value(), average.daily_kcal.value() * 1.15);
+        assert_eq!(border_collie.nutrients, average.nutrients);
+    }
+
+    #[test]
+    fn seniors_need_less_energy_but_the_same_nutrients() {
+        let adult_intake = adult(20.0, ActivityLevel::Moderate, GuidelineStandard::Fediaf);
+        let senior = Intake::new_recommended_intake(&Inputs {
+            age: Age::Senior,
+            weight: 20.0,
+            ..Default::default()
+        });
+        assert!(senior.daily_kcal < adult_intake.daily_kcal);
+        assert_eq!(senior.nutrients, adult_intake.nutrients);
+    }
+
+    /// Unit values are stored in SI base units, so conversions may differ from
+    /// the exact value in the last bits of an f32.
+    fn assert_equal(name: &str, actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-6,
+            "{name}: expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn unit_arithmetic() {
+        let (a, b) = (Gram::from_value(2.5), Gram::from_value(1.5));
+        assert_equal("add", (a + b).value(), 4.0);
+        assert_equal("sub", (a - b).value(), 1.0);
+        assert_equal("mul", (a * 3.0).value(), 7.5);
+        assert_equal("div", a / b, 2.5 / 1.5);
+        assert_equal("sum", [a, b, a].into_iter().sum::<Gram>().value(), 6.5);
+
+        let (a, b) = (Kcal::from_value(1200.0), Kcal::from_value(300.0));
+        assert_equal("kcal add", (a + b).value(), 1500.0);
+        assert_equal("kcal sub", (a - b).value(), 900.0);
+        assert_equal("kcal mul", (a * 0.5).value(), 600.0);
+        assert_equal("kcal div", a / b, 4.0);
+
+        let (a, b) = (IU::<VitaminA>::from_value(1000.0), IU::<VitaminA>::from_value(250.0));
+        assert_eq!((a + b).value(), 1250.0);
+        assert_eq!((a - b).value(), 750.0);
+        assert_eq!((a * 2.0).value(), 2000.0);
+        assert_eq!(a / b, 4.0);
+        assert_eq!([a, b].into_iter().sum::<IU<VitaminA>>().value(), 1250.0);
+    }
+
+    #[test]
+    fn mass_conversions() {
+        assert_equal("g to mg", Milligram::from(Gram::from_value(1.5)).value(), 1500.0);
+        assert_equal("mg to g", Gram::from(Milligram::from_value(250.0)).value(), 0.25);
+        assert_equal("mg to µg", Microgram::from(Milligram::from_value(2.0)).value(), 2000.0);
+        assert_equal("µg to mg", Milligram::from(Microgram::from_value(500.0)).value(), 0.5);
+        assert_equal("g to µg", Microgram::from(Gram::from_value(0.003)).value(), 3000.0);
+        assert_equal("µg to g", Gram::from(Microgram::from_value(2500.0)).value(), 0.0025);
+        assert_equal("kg to g", Gram::from(Kilogram::from_value(1.2)).value(), 1200.0);
+        assert_equal("g to kg", Kilogram::from(Gram::from_value(1500.0)).value(), 1.5);
+    }
+
+    #[test]
+    fn weight_and_energy_conversions() {
+        // 1 lb is exactly 0.45359237 kg
+        assert_equal("lb to kg", Kilogram::new(10.0, UnitSystem::Imperial).get(UnitSystem::Metric), 4.535_923_7);
+        assert_equal("kg to lb", Kilogram::from_value(4.535_923_7).get(UnitSystem::Imperial), 10.0);
+        assert_equal("kg", Kilogram::new(12.5, UnitSystem::Metric).value(), 12.5);
+        let imperial = Units {
+            system: UnitSystem::Imperial,
+            ..Default::default()
+        };
+        assert_eq!(Kilogram::from_value(10.0).display(imperial), "22.0 lb");
+        assert_eq!(Gram::from_value(100.0).display(imperial), "3.527 oz");
+
+        // 1 kcal is exactly 4.184 kJ
+        let kilojoules = Units {
+            energy: EnergyUnit::Kilojoule,
+            ..Default::default()
+        };
+        assert_eq!(Kcal::from_value(1000.0).display(Units::default()), "1000 kcal");
+        assert_eq!(Kcal::from_value(1000.0).display(kilojoules), "4184 kJ");
+        assert_eq!(Kcal::from_value(250.0).display(kilojoules), "1046 kJ");
+    }
+
+    #[test]
+    fn international_units_to_mass() {
+        assert_equal("vitamin A", IU::<VitaminA>::from_value(1000.0).mass().value(), 300.0);
+        assert_equal("vitamin D", IU::<VitaminD>::from_value(400.0).mass().value(), 10.0);
+        assert_equal("vitamin E", IU::<VitaminE>::from_value(10.0).mass().value(), 6700.0);
     }
 }
@@ -0,0 +1,165 @@
+use super::super::shared::types::{Inputs, UnitSystem};
+use super::nutrients::{Amount, FromValue, Intake, Kcal, Kilogram, NewRecommendedIntake};
+use std::fmt;
+
+/// Above 2% of body weight per week dogs lose lean mass, or put on fat instead
+/// of muscle, so faster plans are not offered.
+const MAX_RATE_PERCENTAGE: f32 = 2.0;
+
+/// Plans longer than two years are not realistic to follow.
+const MAX_PLAN_WEEKS: u32 = 104;
+
+/// Energy stored in a kg of body weight, mostly fat, which a weekly loss has to
+/// be taken from the diet (or a gain added to it).
+const KCAL_PER_KG_BODY_WEIGHT: f32 = 7700.0;
+
+/// Resting energy requirement `70 * BW^0.75`, restricted intakes never go below
+/// it at the target weight.
+const RESTING_KCAL_PER_METABOLIC_KG: f32 = 70.0;
+
+/// One week of a weight plan.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlanWeek {
+    /// Starting at week 1.
+    pub week: u32,
+    /// The weight to reach at the end of the week.
+    pub target_weight: Kilogram,
+    /// Energy to feed during the week, the maintenance requirement at the weight
+    /// the week starts at, less the energy of the weight lost (or plus the
+    /// weight gained) that week.
+    pub daily_kcal: Kcal,
+}
+
+/// A week by week plan towards a target weight. Nutrient requirements stay at
+/// those of the target weight throughout, so a restricted energy intake still
+/// supplies every nutrient.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightPlan {
+    pub weeks: Vec<PlanWeek>,
+    pub target_intake: Intake,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlanError {
+    InvalidRate,
+    TooLong,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::InvalidRate => write!(
+                f,
+                "The rate must be above 0% and at most {MAX_RATE_PERCENTAGE}% of body weight per week"
+            ),
+            PlanError::TooLong => write!(f, "The plan would take more than {MAX_PLAN_WEEKS} weeks"),
+        }
+    }
+}
+
+/// Generates a plan from the current weight of the inputs to the target weight,
+/// changing the weight by `rate_percentage` of the current weight every week.
+/// The body condition score is ignored, the plan itself moves the dog to its
+/// ideal weight.
+pub fn weight_plan(
+    inputs: &Inputs,
+    target_weight: Kilogram,
+    rate_percentage: f32,
+) -> Result<WeightPlan, PlanError> {
+    if !(rate_percentage > 0.0 && rate_percentage <= MAX_RATE_PERCENTAGE) {
+        return Err(PlanError::InvalidRate);
+    }
+    let target = target_weight.get(UnitSystem::Metric);
+    let intake_at = |weight: f32| {
+        Intake::new_recommended_intake(&Inputs {
+            weight,
+            body_condition_score: None,
+            ..*inputs
+        })
+    };
+
+    let resting_kcal = RESTING_KCAL_PER_METABOLIC_KG * target.powf(0.75);
+
+    let losing = target < inputs.weight;
+    let factor = if losing { 1.0 - rate_percentage / 100.0 } else { 1.0 + rate_percentage / 100.0 };
+    let mut weeks = Vec::new();
+    let mut weight = inputs.weight;
+    while weight != target {
+        if weeks.len() as u32 == MAX_PLAN_WEEKS {
+            return Err(PlanError::TooLong);
+        }
+        let start = weight;
+        weight = if losing {
+            (weight * factor).max(target)
+        } else {
+            (weight * factor).min(target)
+        };
+        let maintenance = intake_at(start).daily_kcal.value();
+        let change = (weight - start) * KCAL_PER_KG_BODY_WEIGHT / 7.0;
+        weeks.push(PlanWeek {
+            week: weeks.len() as u32 + 1,
+            target_weight: Kilogram::from_value(weight),
+            daily_kcal: Kcal::from_value((maintenance + change).max(resting_kcal)),
+        });
+    }
+
+    Ok(WeightPlan {
+        weeks,
+        target_intake: intake_at(target),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(weight: f32) -> Inputs {
+        Inputs {
+            weight,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn weight_loss_reaches_the_target_with_decreasing_energy() {
+        let plan = weight_plan(&inputs(30.0), Kilogram::from_value(27.0), 1.0).unwrap();
+        let weights: Vec<f32> = plan.weeks.iter().map(|week| week.target_weight.value()).collect();
+        // ln(27 / 30) / ln(0.99) = 10.5 weeks
+        assert_eq!(weights.len(), 11);
+        assert!(weights.windows(2).all(|pair| pair[1] < pair[0]));
+        assert_eq!(*weights.last().unwrap(), 27.0);
+        // The last week only loses what is left, so it needs a smaller deficit
+        let full_weeks = &plan.weeks[..plan.weeks.len() - 1];
+        assert!(full_weeks.windows(2).all(|pair| pair[1].daily_kcal.value() < pair[0].daily_kcal.value()));
+        assert_eq!(plan.target_intake, Intake::new_recommended_intake(&inputs(27.0)));
+    }
+
+    #[test]
+    fn energy_deficit_matches_the_weekly_loss() {
+        let plan = weight_plan(&inputs(30.0), Kilogram::from_value(27.0), 1.0).unwrap();
+        let maintenance = Intake::new_recommended_intake(&inputs(30.0)).daily_kcal.value();
+        // 1% of 30 kg is 0.3 kg in the first week, 0.3 * 7700 / 7 = 330 kcal a day
+        let deficit = maintenance - plan.weeks[0].daily_kcal.value();
+        assert!((deficit - 330.0).abs() < 0.5, "deficit of {deficit} kcal");
+        // Well below maintenance at the target weight
+        assert!(plan.weeks.iter().all(|week| week.daily_kcal.value() < plan.target_intake.daily_kcal.value()));
+
+        // A gain adds the energy of the weight gained
+        let plan = weight_plan(&inputs(20.0), Kilogram::from_value(22.0), 2.0).unwrap();
+        let maintenance = Intake::new_recommended_intake(&inputs(20.0)).daily_kcal.value();
+        let surplus = plan.weeks[0].daily_kcal.value() - maintenance;
+        assert!((surplus - 440.0).abs() < 0.5, "surplus of {surplus} kcal");
+    }
+
+    #[test]
+    fn weight_gain_and_invalid_plans() {
+        let plan = weight_plan(&inputs(20.0), Kilogram::from_value(22.0), 2.0).unwrap();
+        assert!(plan.weeks.windows(2).all(|pair| pair[1].target_weight.value() > pair[0].target_weight.value()));
+        assert_eq!(plan.weeks.last().unwrap().target_weight.value(), 22.0);
+
+        assert!(weight_plan(&inputs(20.0), Kilogram::from_value(20.0), 1.0).unwrap().weeks.is_empty());
+        assert_eq!(weight_plan(&inputs(20.0), Kilogram::from_value(18.0), 2.5), Err(PlanError::InvalidRate));
+        assert_eq!(weight_plan(&inputs(20.0), Kilogram::from_value(18.0), 0.0), Err(PlanError::InvalidRate));
+        assert_eq!(weight_plan(&inputs(80.0), Kilogram::from_value(8.0), 1.0), Err(PlanError::TooLong));
+    }
+}
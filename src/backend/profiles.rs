@@ -0,0 +1,230 @@
+use super::super::shared::types::{ActivityLevel, Age, Inputs, NeuterStatus};
+use super::breeds::{self, Breed};
+use super::diary::DiaryDay;
+use super::nutrients::Amount;
+use super::recipe::Recipe;
+use super::storage::{self, StorageError};
+use serde::{Deserialize, Serialize};
+
+/// Storage key of the list of profiles.
+const PROFILES_KEY: &str = "profiles";
+
+/// A named dog with the inputs that stay the same between visits.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub name: String,
+    /// ISO 8601 date (YYYY-MM-DD), empty when unknown.
+    pub birthdate: String,
+    /// Body weight in kg at the last save.
+    pub weight: f32,
+    /// Name of a breed of the breed dataset, `None` for mixed or unknown breeds.
+    pub breed: Option<String>,
+    pub activity_level: ActivityLevel,
+    pub neuter_status: NeuterStatus,
+    /// Weighings ordered by date.
+    pub weight_log: Vec<WeightEntry>,
+    /// What the dog ate, ordered by date.
+    pub diary: Vec<DiaryDay>,
+}
+
+/// A weighing together with the energy that was being fed at the time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightEntry {
+    /// ISO 8601 date (YYYY-MM-DD).
+    pub date: String,
+    /// Body weight in kg.
+    pub weight: f32,
+    /// The recommended daily energy in kcal that was being fed.
+    pub daily_kcal: f32,
+}
+
+impl Profile {
+    /// Age in full weeks on the given day, `None` without a valid birthdate.
+    pub fn age_weeks(&self, today: i64) -> Option<u32> {
+        let born = days_since_epoch(&self.birthdate)?;
+        u32::try_from((today - born) / 7).ok()
+    }
+
+    /// The age group on the given day, seniors start at the senior age of the
+    /// breed's size class.
+    pub fn age(&self, today: i64) -> Option<Age> {
+        let size_class = self.breed().map(|breed| breed.size_class).unwrap_or_default();
+        Some(size_class.age(self.age_weeks(today)?))
+    }
+
+    fn breed(&self) -> Option<&'static Breed> {
+        self.breed.as_deref().and_then(breeds::find)
+    }
+
+    /// The calculator inputs of the dog on the given day. Without a birthdate
+    /// the dog is taken to be an adult.
+    pub fn inputs(&self, today: i64) -> Inputs {
+        Inputs {
+            age: self.age(today).unwrap_or_default(),
+            weight: self.weight,
+            activity_level: self.activity_level,
+            breed_energy_factor: self.breed().map(|breed| breed.energy_factor),
+            neuter_status: self.neuter_status,
+            age_weeks: self.age_weeks(today),
+            expected_adult_weight: self.breed().map(|breed| breed.expected_adult_weight().value()),
+            ..Default::default()
+        }
+    }
+
+    /// What the dog ate on the date, empty when nothing was logged.
+    pub fn diary_day(&self, date: &str) -> Recipe {
+        self.diary
+            .iter()
+            .find(|day| day.date == date)
+            .map(|day| day.recipe.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replaces what the dog ate on the date, an empty recipe removes the day.
+    /// Dates that are not valid are ignored.
+    pub fn set_diary_day(&mut self, date: &str, recipe: Recipe) {
+        let Some(day) = days_since_epoch(date) else {
+            return;
+        };
+        self.diary.retain(|logged| days_since_epoch(&logged.date) != Some(day));
+        if recipe.is_empty() {
+            return;
+        }
+        let index = self
+            .diary
+            .partition_point(|logged| days_since_epoch(&logged.date).is_some_and(|logged| logged < day));
+        self.diary.insert(index, DiaryDay {
+            date: date.to_string(),
+            recipe,
+        });
+    }
+
+    /// Adds a weighing to the log, replacing an earlier weighing on the same
+    /// date. Entries without a valid date are ignored.
+    pub fn log_weight(&mut self, entry: WeightEntry) {
+        let Some(day) = days_since_epoch(&entry.date) else {
+            return;
+        };
+        self.weight_log
+            .retain(|logged| days_since_epoch(&logged.date) != Some(day));
+        let index = self
+            .weight_log
+            .partition_point(|logged| days_since_epoch(&logged.date).is_some_and(|logged| logged < day));
+        self.weight_log.insert(index, entry);
+    }
+}
+
+/// Days since 1970-01-01 of an ISO 8601 date (YYYY-MM-DD).
+pub fn days_since_epoch(date: &str) -> Option<i64> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=days_in_month(year, month)?).contains(&day) {
+        return None;
+    }
+    // Civil calendar to days, with years starting in March so the leap day is
+    // the last day of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+/// Days in a month of the Gregorian calendar, `None` for months outside 1-12.
+fn days_in_month(year: i64, month: i64) -> Option<i64> {
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    match month {
+        2 if leap_year => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        _ => None,
+    }
+}
+
+/// The ISO 8601 date (YYYY-MM-DD) of a number of days since 1970-01-01.
+pub fn date_from_days(days: i64) -> String {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// The stored profiles, empty when none were saved yet.
+pub fn load() -> Result<Vec<Profile>, StorageError> {
+    match storage::load(PROFILES_KEY)? {
+        Some(json) => serde_json::from_str(&json).map_err(|error| StorageError::Read(error.to_string())),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Replaces the stored profiles.
+pub fn save(profiles: &[Profile]) -> Result<(), StorageError> {
+    let json = serde_json::to_string(profiles).map_err(|error| StorageError::Write(error.to_string()))?;
+    storage::save(PROFILES_KEY, &json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_convert_to_days_since_epoch() {
+        assert_eq!(days_since_epoch("1970-01-01"), Some(0));
+        assert_eq!(days_since_epoch("2000-03-01"), Some(11_017));
+        assert_eq!(days_since_epoch("2024-02-29"), Some(19_782));
+        assert_eq!(days_since_epoch("2024-13-01"), None);
+        assert_eq!(days_since_epoch(""), None);
+        // Days that do not exist in their month
+        assert_eq!(days_since_epoch("2024-02-30"), None);
+        assert_eq!(days_since_epoch("2023-02-29"), None);
+        assert_eq!(days_since_epoch("2023-04-31"), None);
+        assert_eq!(days_since_epoch("2023-04-00"), None);
+        assert_eq!(days_since_epoch("1900-02-29"), None);
+        assert_eq!(days_since_epoch("2000-02-29"), Some(11_016));
+        for days in [0, 11_017, 19_782, 20_000] {
+            assert_eq!(days_since_epoch(&date_from_days(days)), Some(days));
+        }
+    }
+
+    #[test]
+    fn weighings_are_ordered_by_date() {
+        let entry = |date: &str, weight: f32| WeightEntry {
+            date: date.to_string(),
+            weight,
+            daily_kcal: 1000.0,
+        };
+        let mut profile = Profile::default();
+        profile.log_weight(entry("2024-03-01", 20.0));
+        profile.log_weight(entry("2024-01-01", 22.0));
+        profile.log_weight(entry("2024-03-01", 19.5));
+        profile.log_weight(entry("not a date", 30.0));
+        assert_eq!(profile.weight_log, vec![entry("2024-01-01", 22.0), entry("2024-03-01", 19.5)]);
+    }
+
+    #[test]
+    fn age_follows_the_birthdate_and_breed() {
+        let today = days_since_epoch("2024-06-01").unwrap();
+        let profile = |birthdate: &str, breed: Option<&str>| Profile {
+            birthdate: birthdate.to_string(),
+            breed: breed.map(str::to_string),
+            ..Default::default()
+        };
+        let puppy = profile("2024-01-01", None);
+        assert_eq!(puppy.age_weeks(today), Some(21));
+        assert_eq!(puppy.age(today), Some(Age::Puppy));
+        assert_eq!(profile("2017-01-01", Some("Chihuahua")).age(today), Some(Age::Adult));
+        assert_eq!(profile("2017-01-01", Some("Great Dane")).age(today), Some(Age::Senior));
+        assert_eq!(profile("", None).age(today), None);
+    }
+}
@@ -0,0 +1,177 @@
+use super::nutrients::{Amount, Nutrients};
+use super::recipe::Coverage;
+use std::fmt;
+
+/// Calcium to phosphorus, AAFCO and FEDIAF accept 1:1 up to 2:1.
+const CALCIUM_PHOSPHORUS: (f32, f32) = (1.0, 2.0);
+
+/// Zinc to copper, both compete for absorption so neither should dominate.
+const ZINC_COPPER: (f32, f32) = (5.0, 15.0);
+
+/// Omega-6 to omega-3 fatty acids, NRC 2006 suggests 2.6:1 up to 26:1.
+const OMEGA_6_OMEGA_3: (f32, f32) = (2.6, 26.0);
+
+/// Methionine to methionine + cystine, FEDIAF requires about half of the
+/// combined amount as methionine since cystine can not replace it.
+const METHIONINE_METHIONINE_CYSTINE: (f32, f32) = (0.5, 1.0);
+
+/// Phenylalanine to phenylalanine + tyrosine, FEDIAF requires about half of the
+/// combined amount as phenylalanine since tyrosine can not replace it.
+const PHENYLALANINE_PHENYLALANINE_TYROSINE: (f32, f32) = (0.5, 1.0);
+
+/// The ratio between two nutrients of a diet with its accepted range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatioCheck {
+    pub name: &'static str,
+    /// `None` when the diet contains none of the second nutrient.
+    pub value: Option<f32>,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl RatioCheck {
+    fn new(name: &'static str, numerator: &dyn Amount, denominator: &dyn Amount, range: (f32, f32)) -> Self {
+        RatioCheck {
+            name,
+            value: (denominator.value() > 0.0).then(|| numerator.value() / denominator.value()),
+            min: range.0,
+            max: range.1,
+        }
+    }
+
+    /// A ratio below the accepted range is a deficit of the first nutrient,
+    /// above it an excess.
+    pub fn coverage(&self) -> Option<Coverage> {
+        self.value.map(|value| {
+            if value < self.min {
+                Coverage::Deficit
+            } else if value > self.max {
+                Coverage::Excess
+            } else {
+                Coverage::Adequate
+            }
+        })
+    }
+
+    pub fn range(&self) -> String {
+        format!("{} - {} : 1", self.min, self.max)
+    }
+}
+
+impl fmt::Display for RatioCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{value:.2} : 1"),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+/// The nutrient ratios of a diet that are checked next to the absolute amounts.
+pub fn ratio_checks(nutrients: &Nutrients) -> Vec<RatioCheck> {
+    vec![
+        RatioCheck::new(
+            "Calcium : Phosphorus",
+            &nutrients.minerals.calcium,
+            &nutrients.minerals.phosphorus,
+            CALCIUM_PHOSPHORUS,
+        ),
+        RatioCheck::new(
+            "Zinc : Copper",
+            &nutrients.minerals.zinc,
+            &nutrients.minerals.copper,
+            ZINC_COPPER,
+        ),
+        RatioCheck::new(
+            "Omega-6 : Omega-3",
+            &nutrients.fatty_acids.omega_6(),
+            &nutrients.fatty_acids.omega_3(),
+            OMEGA_6_OMEGA_3,
+        ),
+        RatioCheck::new(
+            "Methionine : Met + Cys",
+            &nutrients.amino_acids.methionine,
+            &nutrients.amino_acids.methionine_cystine(),
+            METHIONINE_METHIONINE_CYSTINE,
+        ),
+        RatioCheck::new(
+            "Phenylalanine : Phe + Tyr",
+            &nutrients.amino_acids.phenylalanine,
+            &nutrients.amino_acids.phenylalanine_tyrosine(),
+            PHENYLALANINE_PHENYLALANINE_TYROSINE,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::{FromValue, Gram, Milligram};
+
+    fn check(nutrients: &Nutrients, name: &str) -> RatioCheck {
+        ratio_checks(nutrients)
+            .into_iter()
+            .find(|ratio| ratio.name == name)
+            .unwrap()
+    }
+
+    fn minerals(calcium: f32, phosphorus: f32, zinc: f32, copper: f32) -> Nutrients {
+        let mut nutrients = Nutrients::default();
+        nutrients.minerals.calcium = Gram::from_value(calcium);
+        nutrients.minerals.phosphorus = Gram::from_value(phosphorus);
+        nutrients.minerals.zinc = Milligram::from_value(zinc);
+        nutrients.minerals.copper = Milligram::from_value(copper);
+        nutrients
+    }
+
+    #[test]
+    fn ratios_at_and_beyond_their_bounds() {
+        let coverage = |nutrients: &Nutrients, name| check(nutrients, name).coverage();
+        let calcium_phosphorus = "Calcium : Phosphorus";
+        let zinc_copper = "Zinc : Copper";
+
+        // The bounds themselves are accepted
+        assert_eq!(coverage(&minerals(1.0, 1.0, 5.0, 1.0), calcium_phosphorus), Some(Coverage::Adequate));
+        assert_eq!(coverage(&minerals(2.0, 1.0, 15.0, 1.0), calcium_phosphorus), Some(Coverage::Adequate));
+        assert_eq!(coverage(&minerals(1.0, 1.0, 5.0, 1.0), zinc_copper), Some(Coverage::Adequate));
+        assert_eq!(coverage(&minerals(2.0, 1.0, 15.0, 1.0), zinc_copper), Some(Coverage::Adequate));
+
+        assert_eq!(coverage(&minerals(0.9, 1.0, 4.9, 1.0), calcium_phosphorus), Some(Coverage::Deficit));
+        assert_eq!(coverage(&minerals(2.1, 1.0, 15.1, 1.0), calcium_phosphorus), Some(Coverage::Excess));
+        assert_eq!(coverage(&minerals(0.9, 1.0, 4.9, 1.0), zinc_copper), Some(Coverage::Deficit));
+        assert_eq!(coverage(&minerals(2.1, 1.0, 15.1, 1.0), zinc_copper), Some(Coverage::Excess));
+
+        let mut fatty_acids = Nutrients::default();
+        fatty_acids.fatty_acids.linoleic_acid = Gram::from_value(2.0);
+        fatty_acids.fatty_acids.arachidonic_acid = Gram::from_value(0.6);
+        fatty_acids.fatty_acids.alpha_linolenic_acid = Gram::from_value(0.5);
+        fatty_acids.fatty_acids.epa_dha = Gram::from_value(0.5);
+        let omega = check(&fatty_acids, "Omega-6 : Omega-3");
+        assert!((omega.value.unwrap() - 2.6).abs() < 1e-5);
+        assert_eq!(omega.coverage(), Some(Coverage::Adequate));
+        fatty_acids.fatty_acids.epa_dha = Gram::from_value(0.6);
+        assert_eq!(check(&fatty_acids, "Omega-6 : Omega-3").coverage(), Some(Coverage::Deficit));
+        fatty_acids.fatty_acids.linoleic_acid = Gram::from_value(30.0);
+        assert_eq!(check(&fatty_acids, "Omega-6 : Omega-3").coverage(), Some(Coverage::Excess));
+    }
+
+    #[test]
+    fn combined_amino_acids() {
+        let mut nutrients = Nutrients::default();
+        nutrients.amino_acids.methionine = Gram::from_value(0.4);
+        nutrients.amino_acids.cystine = Gram::from_value(0.6);
+        nutrients.amino_acids.phenylalanine = Gram::from_value(1.0);
+        nutrients.amino_acids.tyrosine = Gram::from_value(1.0);
+        assert_eq!(check(&nutrients, "Methionine : Met + Cys").coverage(), Some(Coverage::Deficit));
+        assert_eq!(check(&nutrients, "Phenylalanine : Phe + Tyr").coverage(), Some(Coverage::Adequate));
+    }
+
+    #[test]
+    fn zero_denominator_has_no_ratio() {
+        let ratio = check(&minerals(1.0, 0.0, 0.0, 0.0), "Calcium : Phosphorus");
+        assert_eq!(ratio.value, None);
+        assert_eq!(ratio.coverage(), None);
+        assert_eq!(ratio.to_string(), "-");
+        assert!(ratio_checks(&Nutrients::default()).iter().all(|ratio| ratio.value.is_none()));
+    }
+}
@@ -0,0 +1,248 @@
+use super::ingredients;
+use super::nutrients::{Amount, Intake, Kcal, Nutrients};
+use super::storage::{self, StorageError};
+use serde::{Deserialize, Serialize};
+
+/// Storage key of the saved recipes.
+const RECIPES_KEY: &str = "recipes";
+
+/// Without nutrient specific upper limits, anything above three times the
+/// requirement is flagged as an excess.
+const EXCESS_PERCENTAGE: f32 = 300.0;
+
+/// Energy should stay within 10% of the target to keep the dog at its weight.
+const ENERGY_TOLERANCE_PERCENTAGE: f32 = 10.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecipeItem {
+    /// Name of an ingredient in the bundled dataset.
+    pub ingredient: String,
+    pub grams: f32,
+}
+
+/// A daily recipe, a mix of ingredients with their amount in grams.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Recipe {
+    pub items: Vec<RecipeItem>,
+}
+
+impl Recipe {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn total_grams(&self) -> f32 {
+        self.items.iter().map(|item| item.grams).sum()
+    }
+
+    /// The energy and nutrients supplied by the recipe. Ingredients that are
+    /// not in the dataset are ignored.
+    pub fn intake(&self) -> Intake {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                ingredients::find(&item.ingredient).map(|ingredient| {
+                    let factor = item.grams / 100.0;
+                    Intake {
+                        daily_kcal: ingredient.kcal * factor,
+                        nutrients: ingredient.nutrients.clone() * factor,
+                    }
+                })
+            })
+            .sum()
+    }
+
+    /// The same mix of ingredients in the amounts that supply the energy.
+    /// `None` when the recipe supplies no energy.
+    pub fn scaled_to(&self, kcal: Kcal) -> Option<Recipe> {
+        let recipe_kcal = self.intake().daily_kcal.value();
+        if recipe_kcal <= 0.0 {
+            return None;
+        }
+        let factor = kcal.value() / recipe_kcal;
+        Some(Recipe {
+            items: self
+                .items
+                .iter()
+                .map(|item| RecipeItem {
+                    ingredient: item.ingredient.clone(),
+                    grams: item.grams * factor,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// A recipe stored under a name.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedRecipe {
+    pub name: String,
+    pub recipe: Recipe,
+}
+
+/// The saved recipes, empty when none were saved yet.
+pub fn load_saved() -> Result<Vec<SavedRecipe>, StorageError> {
+    match storage::load(RECIPES_KEY)? {
+        Some(json) => serde_json::from_str(&json).map_err(|error| StorageError::Read(error.to_string())),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Replaces the saved recipes.
+pub fn save_saved(recipes: &[SavedRecipe]) -> Result<(), StorageError> {
+    let json = serde_json::to_string(recipes).map_err(|error| StorageError::Write(error.to_string()))?;
+    storage::save(RECIPES_KEY, &json)
+}
+
+/// How well a supplied amount covers a requirement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Coverage {
+    Deficit,
+    Adequate,
+    Excess,
+}
+
+impl Coverage {
+    /// Coverage of a nutrient, given the percentage of the requirement met.
+    pub fn nutrient(percentage_met: f32) -> Self {
+        if percentage_met < 100.0 {
+            Coverage::Deficit
+        } else if percentage_met > EXCESS_PERCENTAGE {
+            Coverage::Excess
+        } else {
+            Coverage::Adequate
+        }
+    }
+
+    /// Coverage of the energy requirement, given the percentage met.
+    pub fn energy(percentage_met: f32) -> Self {
+        if percentage_met < 100.0 - ENERGY_TOLERANCE_PERCENTAGE {
+            Coverage::Deficit
+        } else if percentage_met > 100.0 + ENERGY_TOLERANCE_PERCENTAGE {
+            Coverage::Excess
+        } else {
+            Coverage::Adequate
+        }
+    }
+}
+
+/// Percentage of the required amount that is supplied, `None` when nothing is
+/// required.
+pub fn percentage_met(supplied: &dyn Amount, required: &dyn Amount) -> Option<f32> {
+    if required.value() > 0.0 {
+        Some(supplied.value() / required.value() * 100.0)
+    } else {
+        None
+    }
+}
+
+/// Whether the supplied amount is above a safe upper limit, a limit of zero
+/// means there is no limit.
+pub fn exceeds_limit(supplied: &dyn Amount, limit: &dyn Amount) -> bool {
+    limit.value() > 0.0 && supplied.value() > limit.value()
+}
+
+/// Coverage of a supplied amount against its requirement and safe upper limit,
+/// `None` when nothing is required and the limit is not exceeded. A limit above
+/// zero decides the excess, the `coverage` rule only applies without one.
+pub fn coverage_with_limit(
+    supplied: &dyn Amount,
+    required: &dyn Amount,
+    limit: Option<&dyn Amount>,
+    coverage: fn(f32) -> Coverage,
+) -> Option<Coverage> {
+    let limit = limit.filter(|limit| limit.value() > 0.0);
+    if limit.is_some_and(|limit| exceeds_limit(supplied, limit)) {
+        return Some(Coverage::Excess);
+    }
+    let coverage = coverage(percentage_met(supplied, required)?);
+    match (limit, coverage) {
+        (Some(_), Coverage::Excess) => Some(Coverage::Adequate),
+        (_, coverage) => Some(coverage),
+    }
+}
+
+/// A nutrient supplied above its safe upper limit.
+pub struct LimitWarning<'a> {
+    pub nutrient: &'static str,
+    pub supplied: &'a dyn Amount,
+    pub limit: &'a dyn Amount,
+}
+
+/// Every nutrient of the supplied amounts that exceeds its upper limit.
+pub fn limit_warnings<'a>(supplied: &'a Nutrients, upper_limits: &'a Nutrients) -> Vec<LimitWarning<'a>> {
+    supplied
+        .into_iter()
+        .zip(upper_limits)
+        .filter(|((_, supplied), (_, limit))| exceeds_limit(*supplied, *limit))
+        .map(|((nutrient, supplied), (_, limit))| LimitWarning {
+            nutrient,
+            supplied,
+            limit,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::{FromValue, Gram, Milligram};
+    use crate::shared::types::NutrientBasis;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn documented_limits_decide_the_excess() {
+        let required = Milligram::from_value(100.0);
+        let supplied = Milligram::from_value(400.0);
+        let coverage =
+            |limit: Option<&dyn Amount>| coverage_with_limit(&supplied, &required, limit, Coverage::nutrient);
+
+        // 400% of the requirement, but under the documented limit
+        assert_eq!(coverage(Some(&Milligram::from_value(500.0))), Some(Coverage::Adequate));
+        assert_eq!(coverage(Some(&Milligram::from_value(350.0))), Some(Coverage::Excess));
+        // Without a limit, or a limit of zero, the 300% rule applies
+        assert_eq!(coverage(None), Some(Coverage::Excess));
+        assert_eq!(coverage(Some(&Milligram::from_value(0.0))), Some(Coverage::Excess));
+        let limit = Milligram::from_value(500.0);
+        let low = Milligram::from_value(50.0);
+        assert_eq!(
+            coverage_with_limit(&low, &required, Some(&limit), Coverage::nutrient),
+            Some(Coverage::Deficit)
+        );
+        assert_eq!(coverage_with_limit(&supplied, &Milligram::from_value(0.0), None, Coverage::nutrient), None);
+    }
+
+    #[test]
+    fn warnings_and_markers_agree_off_target_energy() {
+        let required = Intake {
+            daily_kcal: Kcal::from_value(1000.0),
+            ..Default::default()
+        };
+        let mut upper_limits = Nutrients::default();
+        upper_limits.minerals.calcium = Gram::from_value(2.0);
+        // Twice the target energy, so the daily calcium is above the limit but
+        // its density is not
+        let mut supplied = Intake {
+            daily_kcal: Kcal::from_value(2000.0),
+            ..Default::default()
+        };
+        supplied.nutrients.minerals.calcium = Gram::from_value(3.0);
+
+        // Converted the way the nutrient table shows them
+        for basis in NutrientBasis::iter() {
+            let supplied = supplied.density(basis);
+            let limits = upper_limits.clone() * required.basis_factor(basis);
+            let warned = limit_warnings(&supplied.nutrients, &limits)
+                .iter()
+                .any(|warning| warning.nutrient == "Calcium");
+            let marked = coverage_with_limit(
+                &supplied.nutrients.minerals.calcium,
+                &supplied.nutrients.minerals.calcium,
+                Some(&limits.minerals.calcium),
+                Coverage::nutrient,
+            ) == Some(Coverage::Excess);
+            assert_eq!(warned, marked, "{basis}");
+            assert_eq!(warned, basis == NutrientBasis::Daily, "{basis}");
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use super::super::shared::types::GuidelineStandard;
+use super::nutrients::{Amount, GrowthStage, Kcal, MetabolicBodyWeight, Nutrients};
+use serde::Deserialize;
+use std::fmt;
+use std::sync::OnceLock;
+
+const REQUIREMENTS_TOML: &str = include_str!("data/requirements.toml");
+
+/// Version of the requirements file format understood by this build.
+const REQUIREMENTS_VERSION: u32 = 1;
+
+/// Environment variable with the path of a requirements file that replaces the
+/// bundled tables, so they can be adjusted without rebuilding.
+const REQUIREMENTS_PATH_VARIABLE: &str = "NUTRIENT_REQUIREMENTS";
+
+/// NRC 2006 expresses the adult allowances for a dog eating 130 kcal per
+/// kg^0.75, adult tables per 1000 kcal are converted at the same intake.
+const NRC_REFERENCE_KCAL: f32 = 130.0;
+
+/// What the amounts of an adult requirement table are expressed per.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Basis {
+    #[default]
+    MetabolicBodyWeight,
+    PerThousandKcal,
+}
+
+/// Growth requirements are expressed per 1000 kcal of metabolizable energy,
+/// since a puppy's nutrient needs follow its (growth driven) energy intake.
+/// Gestating and lactating bitches use the early growth requirements.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct GrowthTables {
+    pub early: Nutrients,
+    pub late: Nutrients,
+    /// Large breeds stay closer to the early growth minerals, standards without
+    /// a separate table use the late growth table.
+    pub late_large_breed: Option<Nutrients>,
+}
+
+/// Safe upper limits per 1000 kcal of metabolizable energy. Nutrients without
+/// a documented limit are left at zero, which means no limit.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct UpperLimitTables {
+    pub adult: Nutrients,
+    /// Also used for gestation and lactation.
+    pub growth: Nutrients,
+}
+
+/// The requirement tables of one guideline standard.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct StandardTables {
+    #[serde(default)]
+    pub adult_basis: Basis,
+    pub adult: Nutrients,
+    pub growth: GrowthTables,
+    #[serde(default)]
+    pub upper_limits: UpperLimitTables,
+}
+
+impl StandardTables {
+    /// Daily requirements of an adult dog for maintenance.
+    pub fn adult(&self, metabolic_bw: &MetabolicBodyWeight) -> Nutrients {
+        let factor = match self.adult_basis {
+            Basis::MetabolicBodyWeight => metabolic_bw.value,
+            Basis::PerThousandKcal => metabolic_bw.value * NRC_REFERENCE_KCAL / 1000.0,
+        };
+        self.adult.clone() * factor
+    }
+
+    /// Daily requirements during growth, gestation or lactation.
+    pub fn growth(&self, daily_kcal: &Kcal, stage: GrowthStage) -> Nutrients {
+        let table = match stage {
+            GrowthStage::Early => &self.growth.early,
+            GrowthStage::Late { large_breed: true } => {
+                self.growth.late_large_breed.as_ref().unwrap_or(&self.growth.late)
+            }
+            GrowthStage::Late { large_breed: false } => &self.growth.late,
+        };
+        table.clone() * (daily_kcal.value() / 1000.0)
+    }
+
+    /// Daily safe upper limits for the energy requirement.
+    pub fn upper_limits(&self, daily_kcal: &Kcal, growth: bool) -> Nutrients {
+        let table = if growth { &self.upper_limits.growth } else { &self.upper_limits.adult };
+        table.clone() * (daily_kcal.value() / 1000.0)
+    }
+}
+
+/// The requirement tables of every guideline standard.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Requirements {
+    pub version: u32,
+    pub nrc: StandardTables,
+    pub aafco: StandardTables,
+    pub fediaf: StandardTables,
+}
+
+impl Requirements {
+    pub fn parse(toml: &str) -> Result<Self, RequirementsError> {
+        let requirements: Requirements =
+            toml::from_str(toml).map_err(|error| RequirementsError::Parse(error.to_string()))?;
+        if requirements.version != REQUIREMENTS_VERSION {
+            return Err(RequirementsError::UnsupportedVersion(requirements.version));
+        }
+        Ok(requirements)
+    }
+
+    pub fn standard(&self, standard: GuidelineStandard) -> &StandardTables {
+        match standard {
+            GuidelineStandard::Nrc => &self.nrc,
+            GuidelineStandard::Aafco => &self.aafco,
+            GuidelineStandard::Fediaf => &self.fediaf,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequirementsError {
+    Read(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for RequirementsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementsError::Read(error) => write!(f, "Requirements file could not be read: {error}"),
+            RequirementsError::Parse(error) => write!(f, "Requirements file is invalid: {error}"),
+            RequirementsError::UnsupportedVersion(version) => write!(
+                f,
+                "Requirements file version {version} is not supported, expected {REQUIREMENTS_VERSION}"
+            ),
+        }
+    }
+}
+
+struct Loaded {
+    requirements: Requirements,
+    override_error: Option<RequirementsError>,
+}
+
+/// Loads the requirements file named by the environment variable, or the
+/// bundled tables when it is not set. An invalid override falls back to the
+/// bundled tables and keeps the error to show to the user.
+fn loaded() -> &'static Loaded {
+    static LOADED: OnceLock<Loaded> = OnceLock::new();
+    LOADED.get_or_init(|| {
+        let bundled = || {
+            Requirements::parse(REQUIREMENTS_TOML).expect("bundled requirement tables should be valid")
+        };
+        let Some(path) = std::env::var_os(REQUIREMENTS_PATH_VARIABLE) else {
+            return Loaded {
+                requirements: bundled(),
+                override_error: None,
+            };
+        };
+        match std::fs::read_to_string(path)
+            .map_err(|error| RequirementsError::Read(error.to_string()))
+            .and_then(|toml| Requirements::parse(&toml))
+        {
+            Ok(requirements) => Loaded {
+                requirements,
+                override_error: None,
+            },
+            Err(error) => Loaded {
+                requirements: bundled(),
+                override_error: Some(error),
+            },
+        }
+    })
+}
+
+/// The requirement tables of a guideline standard.
+pub fn tables(standard: GuidelineStandard) -> &'static StandardTables {
+    loaded().requirements.standard(standard)
+}
+
+/// Why the requirements file of the environment variable was not used.
+pub fn override_error() -> Option<&'static RequirementsError> {
+    loaded().override_error.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::nutrients::{FromValue, Kilogram};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn bundled_tables_cover_every_standard_and_stage() {
+        let requirements = Requirements::parse(REQUIREMENTS_TOML).unwrap();
+        let metabolic_bw = MetabolicBodyWeight::new(Kilogram::from_value(20.0));
+        let daily_kcal = Kcal::from_value(1000.0);
+        let stages = [
+            GrowthStage::Early,
+            GrowthStage::Late { large_breed: false },
+            GrowthStage::Late { large_breed: true },
+        ];
+        for standard in GuidelineStandard::iter() {
+            let tables = requirements.standard(standard);
+            assert!(tables.adult(&metabolic_bw).protein.value() > 0.0, "{standard:?} adult");
+            for stage in stages {
+                let growth = tables.growth(&daily_kcal, stage);
+                assert!(growth.protein.value() > 0.0, "{standard:?} {stage:?}");
+                assert!(growth.minerals.calcium.value() > 0.0, "{standard:?} {stage:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let toml = REQUIREMENTS_TOML.replacen("version = 1", "version = 2", 1);
+        assert_eq!(Requirements::parse(&toml), Err(RequirementsError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn rejects_malformed_tables() {
+        let wrong_type = REQUIREMENTS_TOML.replacen("protein = 3.28", "protein = \"high\"", 1);
+        assert!(matches!(Requirements::parse(&wrong_type), Err(RequirementsError::Parse(_))));
+        let missing_standards = "version = 1\n";
+        assert!(matches!(Requirements::parse(missing_standards), Err(RequirementsError::Parse(_))));
+        let broken_syntax = REQUIREMENTS_TOML.replacen("[nrc.adult]", "[nrc.adult", 1);
+        assert!(matches!(Requirements::parse(&broken_syntax), Err(RequirementsError::Parse(_))));
+    }
+}
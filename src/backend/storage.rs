@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Directory in the user data directory that holds the stored values on
+/// desktop, one file per key.
+#[cfg(all(feature = "desktop", not(feature = "web")))]
+const DATA_DIRECTORY: &str = "nutrient_calculator";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StorageError {
+    /// The platform has no local storage, e.g. private browsing.
+    Unavailable,
+    Read(String),
+    Write(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::Unavailable => write!(f, "Local storage is not available"),
+            StorageError::Read(error) => write!(f, "Stored data could not be read: {error}"),
+            StorageError::Write(error) => write!(f, "Data could not be stored: {error}"),
+        }
+    }
+}
+
+/// Reads the value stored under the key, `None` when nothing was stored yet.
+#[cfg(feature = "web")]
+pub fn load(key: &str) -> Result<Option<String>, StorageError> {
+    local_storage()?
+        .get_item(key)
+        .map_err(|error| StorageError::Read(format!("{error:?}")))
+}
+
+/// Stores the value under the key, replacing the previous value.
+#[cfg(feature = "web")]
+pub fn save(key: &str, value: &str) -> Result<(), StorageError> {
+    local_storage()?
+        .set_item(key, value)
+        .map_err(|error| StorageError::Write(format!("{error:?}")))
+}
+
+#[cfg(feature = "web")]
+fn local_storage() -> Result<web_sys::Storage, StorageError> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .ok_or(StorageError::Unavailable)
+}
+
+/// Reads the value stored under the key, `None` when nothing was stored yet.
+#[cfg(all(feature = "desktop", not(feature = "web")))]
+pub fn load(key: &str) -> Result<Option<String>, StorageError> {
+    match std::fs::read_to_string(path(key)?) {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(StorageError::Read(error.to_string())),
+    }
+}
+
+/// Stores the value under the key, replacing the previous value.
+#[cfg(all(feature = "desktop", not(feature = "web")))]
+pub fn save(key: &str, value: &str) -> Result<(), StorageError> {
+    let path = path(key)?;
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory).map_err(|error| StorageError::Write(error.to_string()))?;
+    }
+    std::fs::write(path, value).map_err(|error| StorageError::Write(error.to_string()))
+}
+
+#[cfg(all(feature = "desktop", not(feature = "web")))]
+fn path(key: &str) -> Result<std::path::PathBuf, StorageError> {
+    dirs::data_dir()
+        .map(|directory| directory.join(DATA_DIRECTORY).join(format!("{key}.json")))
+        .ok_or(StorageError::Unavailable)
+}
+
+/// Reads the value stored under the key, `None` when nothing was stored yet.
+#[cfg(not(any(feature = "web", feature = "desktop")))]
+pub fn load(_key: &str) -> Result<Option<String>, StorageError> {
+    Err(StorageError::Unavailable)
+}
+
+/// Stores the value under the key, replacing the previous value.
+#[cfg(not(any(feature = "web", feature = "desktop")))]
+pub fn save(_key: &str, _value: &str) -> Result<(), StorageError> {
+    Err(StorageError::Unavailable)
+}
+
+/// Today as days since 1970-01-01, from the browser clock on the web.
+#[cfg(feature = "web")]
+pub fn today() -> i64 {
+    (web_sys::js_sys::Date::now() / 86_400_000.0).floor() as i64
+}
+
+/// Today as days since 1970-01-01.
+#[cfg(not(feature = "web"))]
+pub fn today() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| (duration.as_secs() / 86_400) as i64)
+}
@@ -0,0 +1,170 @@
+use crate::backend::batch::{container_kcal, plan_batch, BatchDog};
+use crate::backend::nutrients::{Amount, FromValue, Gram, Intake, Kilogram, NewRecommendedIntake};
+use crate::backend::{profiles, recipe, storage};
+use crate::shared::types::Units;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// Meal prep page, a batch of saved recipes for several saved dogs over a
+/// number of days, split into containers.
+#[component]
+pub fn MealPrep() -> Element {
+    let units = use_context::<Signal<Units>>();
+    let loaded_profiles = use_hook(profiles::load);
+    let loaded_recipes = use_hook(recipe::load_saved);
+    let errors: Vec<String> = [
+        loaded_profiles.as_ref().err().map(|error| error.to_string()),
+        loaded_recipes.as_ref().err().map(|error| error.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let saved_profiles = loaded_profiles.unwrap_or_default();
+    let saved_recipes = loaded_recipes.unwrap_or_default();
+    // Dogs in the batch with the name of the recipe they are fed
+    let mut assignments: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut days: Signal<u32> = use_signal(|| 14);
+    let mut days_per_container: Signal<u32> = use_signal(|| 1);
+
+    let today = storage::today();
+    let dogs: Vec<(String, Intake, recipe::Recipe)> = saved_profiles
+        .iter()
+        .filter_map(|profile| {
+            let recipe_name = assignments().get(&profile.name)?.clone();
+            let recipe = saved_recipes.iter().find(|saved| saved.name == recipe_name)?;
+            Some((
+                profile.name.clone(),
+                Intake::new_recommended_intake(&profile.inputs(today)),
+                recipe.recipe.clone(),
+            ))
+        })
+        .collect();
+    let batch_dogs: Vec<BatchDog> = dogs
+        .iter()
+        .map(|(name, intake, recipe)| BatchDog { name, intake, recipe })
+        .collect();
+    let batch = (!batch_dogs.is_empty()).then(|| plan_batch(&batch_dogs, days(), days_per_container()));
+
+    rsx! {
+        div { class: "container w-[70%] mx-auto px-4 py-8",
+            ul { class: "w-[70%] mx-auto flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+                div { class: "calculator-item-header", "Days in the Batch and Days per Container" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    div { class: "flex justify-center gap-2 text-center m-1",
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            value: "{days}",
+                            class: "number-input",
+                            id: "batchDaysInput",
+                            oninput: move |event| days.set(event.parsed::<u32>().unwrap_or(0))
+                        }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            value: "{days_per_container}",
+                            class: "number-input",
+                            id: "daysPerContainerInput",
+                            oninput: move |event| days_per_container.set(event.parsed::<u32>().unwrap_or(0))
+                        }
+                    }
+                }
+                div { class: "calculator-item-header", "Dogs and Recipes" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    {saved_profiles.iter().map(|profile| {
+                        let dog = profile.name.clone();
+                        rsx! {
+                            div { class: "flex justify-center items-center gap-2 text-center m-1",
+                                span { class: "w-1/3 text-right", "{profile.name}" }
+                                select {
+                                    class: "number-input",
+                                    onchange: move |event| {
+                                        match event.value().as_str() {
+                                            "" => assignments.write().remove(&dog),
+                                            recipe_name => assignments.write().insert(dog.clone(), recipe_name.to_string()),
+                                        };
+                                    },
+                                    option { value: "", "Not in this batch" }
+                                    {saved_recipes.iter().map(|saved| rsx! {
+                                        option {
+                                            value: "{saved.name}",
+                                            selected: assignments().get(&profile.name) == Some(&saved.name),
+                                            "{saved.name}"
+                                        }
+                                    })}
+                                }
+                            }
+                        }
+                    })}
+                    if saved_profiles.is_empty() || saved_recipes.is_empty() {
+                        div { class: "text-center m-1",
+                            "Save dog profiles and recipes in the calculator to plan a batch"
+                        }
+                    }
+                    {errors.iter().map(|error| rsx! {
+                        div { class: "input-error", "{error}" }
+                    })}
+                    if let Some(Err(error)) = &batch {
+                        div { class: "input-error", "{error}" }
+                    }
+                }
+            }
+
+            if let Some(Ok(batch)) = batch {
+                div { class: "mt-4",
+                    h1 { class: "text-2xl font-bold text-center mb-6", "Containers per Dog" }
+                    table { class: "w-[80%] mx-auto mb-6 bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
+                        thead { class: "bg-gray-100 dark:bg-gray-700",
+                            tr {
+                                th { class: "table-header", "Dog" }
+                                th { class: "table-header text-right", "Daily Calories" }
+                                th { class: "table-header text-right", "Containers" }
+                                th { class: "table-header text-right", "Per Container" }
+                                th { class: "table-header text-right", "Last Container" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
+                            {batch.dogs.iter().map(|dog| rsx! {
+                                tr { class: "table-row-alt",
+                                    td { class: "table-cell-label", "{dog.name}" }
+                                    td { class: "table-cell-value text-right", {dog.daily_kcal.display(units())} }
+                                    td { class: "table-cell-value text-right", "{dog.containers}" }
+                                    td { class: "table-cell-value text-right",
+                                        {Gram::from_value(dog.container_grams).display(units())}
+                                        " ("
+                                        {container_kcal(dog, days_per_container()).display(units())}
+                                        ")"
+                                    }
+                                    td { class: "table-cell-value text-right",
+                                        {Gram::from_value(dog.last_container_grams).display(units())}
+                                    }
+                                }
+                            })}
+                        }
+                    }
+                    h1 { class: "text-2xl font-bold text-center mb-6",
+                        "Ingredients for {days} Days ({Kilogram::from_value(batch.total_grams() / 1000.0).display(units())})"
+                    }
+                    table { class: "w-[80%] mx-auto mb-6 bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
+                        thead { class: "bg-gray-100 dark:bg-gray-700",
+                            tr {
+                                th { class: "table-header", "Ingredient" }
+                                th { class: "table-header text-right", "Total" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
+                            {batch.ingredients.iter().map(|item| rsx! {
+                                tr { class: "table-row-alt",
+                                    td { class: "table-cell-label", "{item.ingredient}" }
+                                    td { class: "table-cell-value text-right",
+                                        {Kilogram::from_value(item.grams / 1000.0).display(units())}
+                                    }
+                                }
+                            })}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
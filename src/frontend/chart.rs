@@ -0,0 +1,116 @@
+use crate::backend::nutrients::{Amount, FromValue, Kcal, Kilogram};
+use crate::backend::profiles::{date_from_days, days_since_epoch, WeightEntry};
+use crate::shared::types::Units;
+use dioxus::prelude::*;
+
+const WIDTH: f32 = 600.0;
+const HEIGHT: f32 = 300.0;
+/// Room for the weight labels on the left and the energy labels on the right.
+const MARGIN_X: f32 = 70.0;
+const MARGIN_TOP: f32 = 20.0;
+const MARGIN_BOTTOM: f32 = 40.0;
+
+/// The range of the values with some room above and below, values that are
+/// all the same get a range around them.
+fn value_range(values: impl Iterator<Item = f32>) -> (f32, f32) {
+    let (min, max) = values.fold((f32::MAX, f32::MIN), |(min, max), value| (min.min(value), max.max(value)));
+    let padding = ((max - min) * 0.05).max(1.0);
+    (min - padding, max + padding)
+}
+
+/// Maps a value in `range` to a coordinate between `from` and `to`.
+fn scale(value: f32, range: (f32, f32), from: f32, to: f32) -> f32 {
+    from + (value - range.0) / (range.1 - range.0) * (to - from)
+}
+
+/// Line chart of the weight log with the ideal weight band behind it and the
+/// daily energy that was fed as a dashed line on its own scale.
+#[component]
+pub fn WeightChart(entries: Vec<WeightEntry>, ideal_band: (Kilogram, Kilogram)) -> Element {
+    let units = use_context::<Signal<Units>>();
+    let system = units().system;
+    let points: Vec<(i64, f32, f32)> = entries
+        .iter()
+        .filter_map(|entry| {
+            let day = days_since_epoch(&entry.date)?;
+            Some((day, Kilogram::from_value(entry.weight).get(system), entry.daily_kcal))
+        })
+        .collect();
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return rsx! {
+            div { class: "text-center m-1", "No weighings logged yet" }
+        };
+    };
+
+    // Without a current weight there is no ideal weight to show
+    let band = (ideal_band.1.value() > 0.0).then(|| (ideal_band.0.get(system), ideal_band.1.get(system)));
+    let weight_range = value_range(points.iter().map(|point| point.1).chain(band.into_iter().flat_map(|band| [band.0, band.1])));
+    let kcal_range = value_range(points.iter().map(|point| point.2));
+    // A single weighing is drawn in the middle
+    let day_range = if first.0 == last.0 {
+        (first.0 as f32 - 1.0, first.0 as f32 + 1.0)
+    } else {
+        (first.0 as f32, last.0 as f32)
+    };
+    let x = |day: i64| scale(day as f32, day_range, MARGIN_X, WIDTH - MARGIN_X);
+    let y_weight = |weight: f32| scale(weight, weight_range, HEIGHT - MARGIN_BOTTOM, MARGIN_TOP);
+    let y_kcal = |kcal: f32| scale(kcal, kcal_range, HEIGHT - MARGIN_BOTTOM, MARGIN_TOP);
+
+    let weight_line = points
+        .iter()
+        .map(|point| format!("{:.1},{:.1}", x(point.0), y_weight(point.1)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let kcal_line = points
+        .iter()
+        .map(|point| format!("{:.1},{:.1}", x(point.0), y_kcal(point.2)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let weight_label = |weight: f32| Kilogram::from_value(weight).display(units());
+    let kcal_label = |kcal: f32| Kcal::from_value(kcal).display(units());
+
+    rsx! {
+        svg {
+            view_box: "0 0 {WIDTH} {HEIGHT}",
+            class: "w-full",
+            if let Some(band) = band {
+                rect {
+                    class: "chart-band",
+                    x: "{MARGIN_X}",
+                    y: "{y_weight(band.1)}",
+                    width: "{WIDTH - 2.0 * MARGIN_X}",
+                    height: "{y_weight(band.0) - y_weight(band.1)}",
+                }
+            }
+            polyline { class: "chart-kcal", points: "{kcal_line}" }
+            polyline { class: "chart-weight", points: "{weight_line}" }
+            {points.iter().map(|point| rsx! {
+                circle {
+                    class: "chart-point",
+                    cx: "{x(point.0)}",
+                    cy: "{y_weight(point.1)}",
+                    r: "4",
+                    title { "{date_from_days(point.0)}: {weight_label(point.1)}, {kcal_label(point.2)}" }
+                }
+            })}
+            // Axis labels, weight on the left and energy on the right
+            text { class: "chart-label", x: "5", y: "{MARGIN_TOP + 5.0}", {weight_label(weight_range.1)} }
+            text { class: "chart-label", x: "5", y: "{HEIGHT - MARGIN_BOTTOM}", {weight_label(weight_range.0)} }
+            text { class: "chart-label", x: "{WIDTH - MARGIN_X + 5.0}", y: "{MARGIN_TOP + 5.0}", {kcal_label(kcal_range.1)} }
+            text { class: "chart-label", x: "{WIDTH - MARGIN_X + 5.0}", y: "{HEIGHT - MARGIN_BOTTOM}", {kcal_label(kcal_range.0)} }
+            text { class: "chart-label", x: "{MARGIN_X}", y: "{HEIGHT - 10.0}", "{date_from_days(first.0)}" }
+            text {
+                class: "chart-label",
+                x: "{WIDTH - MARGIN_X}",
+                y: "{HEIGHT - 10.0}",
+                text_anchor: "end",
+                "{date_from_days(last.0)}"
+            }
+        }
+        div { class: "flex justify-center gap-4 text-sm m-1",
+            span { class: "chart-legend-weight", "Weight" }
+            span { class: "chart-legend-kcal", "Daily calories fed" }
+            span { class: "chart-legend-band", "Ideal weight" }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::backend::nutrients::{Amount, Intake, Kcal, Nutrients};
+use crate::backend::ratios::{ratio_checks, RatioCheck};
+use crate::backend::recipe::{coverage_with_limit, limit_warnings, percentage_met, Coverage};
+use crate::shared::types::{NutrientBasis, Units};
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+/// Table listing every nutrient row of a `Nutrients` value, grouped by section.
+/// When a supplied intake is given, every row also shows the supplied amount
+/// and the percentage of the requirement that it meets. When upper limits are
+/// given they are shown in their own column, and supplied amounts above them
+/// are listed as warnings above the table. All amounts can be switched between
+/// daily amounts and nutrient densities, and with `meals` (the fraction of the
+/// day of every meal) daily amounts can be shown per meal.
+#[component]
+pub fn NutrientTable(
+    kcal_label: &'static str,
+    kcal: Kcal,
+    nutrients: Nutrients,
+    supplied: Option<Intake>,
+    upper_limits: Option<Nutrients>,
+    meals: Option<Vec<f32>>,
+) -> Element {
+    let units = use_context::<Signal<Units>>()();
+    let mut basis = use_signal(NutrientBasis::default);
+    // `None` shows the whole day
+    let mut meal: Signal<Option<usize>> = use_signal(|| None);
+    let meals = meals.filter(|meals| meals.len() > 1).unwrap_or_default();
+    let meal_fraction = match meal() {
+        Some(index) if basis() == NutrientBasis::Daily => meals.get(index).copied(),
+        _ => None,
+    };
+    let columns = 2 + if supplied.is_some() { 2 } else { 0 } + if upper_limits.is_some() { 1 } else { 0 };
+    let required = Intake { daily_kcal: kcal, nutrients };
+    let factor = required.basis_factor(basis()) * meal_fraction.unwrap_or(1.0);
+    let supplied = supplied.map(|supplied| supplied.density(basis()) * meal_fraction.unwrap_or(1.0));
+    let upper_limits = upper_limits.map(|limits| limits * factor);
+    let required = required.density(basis()) * meal_fraction.unwrap_or(1.0);
+    let (kcal, nutrients) = (required.daily_kcal, required.nutrients);
+    // Limits are checked on the shown amounts, the same basis as the limit markers
+    let warnings = match (&supplied, &upper_limits) {
+        (Some(supplied), Some(limits)) => limit_warnings(&supplied.nutrients, limits)
+            .into_iter()
+            .map(|warning| {
+                format!(
+                    "{}: {} supplied, limit {}",
+                    warning.nutrient,
+                    warning.supplied.display(units),
+                    warning.limit.display(units)
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let kcal_label = match (basis(), meal_fraction) {
+        (NutrientBasis::Daily, None) => kcal_label,
+        (NutrientBasis::Daily, Some(_)) => "Calories per Meal",
+        _ => "Energy",
+    };
+
+    let limits = upper_limits.as_ref();
+    // Ratios are checked on the supplied diet, or on the listed amounts without one
+    let ratios = ratio_checks(supplied.as_ref().map_or(&nutrients, |supplied| &supplied.nutrients));
+    let supplied_met_cys = supplied.as_ref().map(|s| s.nutrients.amino_acids.methionine_cystine());
+    let supplied_phe_tyr = supplied.as_ref().map(|s| s.nutrients.amino_acids.phenylalanine_tyrosine());
+    rsx! {
+        if !warnings.is_empty() {
+            div { class: "limit-warning",
+                h2 { class: "font-bold", "Above safe upper limit" }
+                ul {
+                    {warnings.iter().map(|warning| rsx! {
+                        li { "{warning}" }
+                    })}
+                }
+            }
+        }
+        div { class: "flex justify-center gap-2 mb-2",
+            {NutrientBasis::iter().map(|variant| rsx! {
+                button {
+                    class: "selectable-button",
+                    aria_pressed: if basis() == variant { false } else { true },
+                    onclick: move |_| basis.set(variant),
+                    "{variant}"
+                }
+            })}
+        }
+        // Daily amounts per meal
+        if basis() == NutrientBasis::Daily && !meals.is_empty() {
+            div { class: "flex justify-center gap-2 mb-2",
+                button {
+                    class: "selectable-button",
+                    aria_pressed: if meal_fraction.is_none() { false } else { true },
+                    onclick: move |_| meal.set(None),
+                    "Whole Day"
+                }
+                {meals.iter().enumerate().map(|(index, fraction)| rsx! {
+                    button {
+                        class: "selectable-button",
+                        aria_pressed: if meal_fraction.is_some() && meal() == Some(index) { false } else { true },
+                        onclick: move |_| meal.set(Some(index)),
+                        "Meal {index + 1} ({fraction * 100.0:.0}%)"
+                    }
+                })}
+            }
+        }
+        table { class: "w-[80%] mx-auto bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
+            thead { class: "bg-gray-100 dark:bg-gray-700",
+                tr {
+                    th { class: "table-header", "Category" }
+                    if supplied.is_some() {
+                        th { class: "table-header text-right", "Supplied" }
+                        th { class: "table-header text-right", "Required" }
+                    } else {
+                        th { class: "table-header text-right", "Value" }
+                    }
+                    if limits.is_some() {
+                        th { class: "table-header text-right", "Upper Limit" }
+                    }
+                    if supplied.is_some() {
+                        th { class: "table-header text-right", "Met" }
+                    }
+                }
+            }
+            tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
+                // Main nutrients section
+                {nutrient_row(kcal_label, &kcal, supplied.as_ref().map(|s| &s.daily_kcal as &dyn Amount), limits.map(|_| None), Coverage::energy, units)}
+                {nutrient_row("Protein", &nutrients.protein, supplied.as_ref().map(|s| &s.nutrients.protein as &dyn Amount), limits.map(|l| Some(&l.protein as &dyn Amount)), Coverage::nutrient, units)}
+                {nutrient_row("Fat", &nutrients.fat, supplied.as_ref().map(|s| &s.nutrients.fat as &dyn Amount), limits.map(|l| Some(&l.fat as &dyn Amount)), Coverage::nutrient, units)}
+
+                // Amino Acids section
+                tr {
+                    td { class: "table-section-header", colspan: columns, "Amino Acids" }
+                }
+                {section_rows(&nutrients.amino_acids, supplied.as_ref().map(|s| &s.nutrients.amino_acids), limits.map(|l| &l.amino_acids), units)}
+
+                // Fatty Acids section
+                tr {
+                    td { class: "table-section-header", colspan: columns, "Fatty Acids" }
+                }
+                {section_rows(&nutrients.fatty_acids, supplied.as_ref().map(|s| &s.nutrients.fatty_acids), limits.map(|l| &l.fatty_acids), units)}
+
+                // Minerals section
+                tr {
+                    td { class: "table-section-header", colspan: columns, "Minerals" }
+                }
+                {section_rows(&nutrients.minerals, supplied.as_ref().map(|s| &s.nutrients.minerals), limits.map(|l| &l.minerals), units)}
+
+                // Vitamins section
+                tr {
+                    td { class: "table-section-header", colspan: columns, "Vitamins" }
+                }
+                {section_rows(&nutrients.vitamins, supplied.as_ref().map(|s| &s.nutrients.vitamins), limits.map(|l| &l.vitamins), units)}
+
+                // Ratios and combined amino acids section
+                tr {
+                    td { class: "table-section-header", colspan: columns, "Ratios" }
+                }
+                {ratios.iter().map(|ratio| ratio_row(ratio, supplied.is_some(), limits.is_some()))}
+                {nutrient_row("Methionine + Cystine", &nutrients.amino_acids.methionine_cystine(), supplied_met_cys.as_ref().map(|s| s as &dyn Amount), limits.map(|_| None), Coverage::nutrient, units)}
+                {nutrient_row("Phenylalanine + Tyrosine", &nutrients.amino_acids.phenylalanine_tyrosine(), supplied_phe_tyr.as_ref().map(|s| s as &dyn Amount), limits.map(|_| None), Coverage::nutrient, units)}
+            }
+        }
+    }
+}
+
+/// Rows of one section, pairing every required amount with its supplied amount
+/// and upper limit.
+fn section_rows<'a, T>(
+    required: &'a T,
+    supplied: Option<&'a T>,
+    limits: Option<&'a T>,
+    units: Units,
+) -> Element
+where
+    &'a T: IntoIterator<Item = (&'static str, &'a dyn Amount)>,
+{
+    let supplied: Vec<&dyn Amount> = supplied
+        .map(|supplied| supplied.into_iter().map(|(_, value)| value).collect())
+        .unwrap_or_default();
+    let limits: Option<Vec<&dyn Amount>> =
+        limits.map(|limits| limits.into_iter().map(|(_, value)| value).collect());
+
+    rsx! {
+        {required.into_iter().enumerate().map(|(index, (name, value))| {
+            let limit = limits.as_ref().map(|limits| limits.get(index).copied());
+            nutrient_row(name, value, supplied.get(index).copied(), limit, Coverage::nutrient, units)
+        })}
+    }
+}
+
+/// A single table row. The limit is `None` when the table has no upper limit
+/// column, and `Some(None)` when the row has no limit of its own.
+fn nutrient_row(
+    name: &str,
+    required: &dyn Amount,
+    supplied: Option<&dyn Amount>,
+    limit: Option<Option<&dyn Amount>>,
+    coverage: fn(f32) -> Coverage,
+    units: Units,
+) -> Element {
+    let limit_cell = limit.map(|limit| match limit {
+        Some(limit) if limit.value() > 0.0 => limit.display(units),
+        _ => "-".to_string(),
+    });
+
+    let Some(supplied) = supplied else {
+        return rsx! {
+            tr { class: "table-row-alt",
+                td { class: "table-cell-label", "{name}" }
+                td { class: "table-cell-value text-right", {required.display(units)} }
+                if let Some(limit_cell) = limit_cell {
+                    td { class: "table-cell-value text-right", "{limit_cell}" }
+                }
+            }
+        };
+    };
+
+    let met = percentage_met(supplied, required);
+    let marker = match coverage_with_limit(supplied, required, limit.flatten(), coverage) {
+        Some(Coverage::Deficit) => "coverage-deficit",
+        Some(Coverage::Excess) => "coverage-excess",
+        Some(Coverage::Adequate) => "coverage-adequate",
+        None => "",
+    };
+
+    rsx! {
+        tr { class: "table-row-alt",
+            td { class: "table-cell-label", "{name}" }
+            td { class: "table-cell-value text-right", {supplied.display(units)} }
+            td { class: "table-cell-value text-right", {required.display(units)} }
+            if let Some(limit_cell) = limit_cell {
+                td { class: "table-cell-value text-right", "{limit_cell}" }
+            }
+            td { class: "table-cell-value text-right {marker}",
+                match met {
+                    Some(percentage) => format!("{percentage:.0}%"),
+                    None => "-".to_string(),
+                }
+            }
+        }
+    }
+}
+
+/// A ratio row, showing the ratio in the value column and the accepted range in
+/// the required column when there is one.
+fn ratio_row(ratio: &RatioCheck, with_supplied: bool, with_limits: bool) -> Element {
+    if !with_supplied {
+        return rsx! {
+            tr { class: "table-row-alt",
+                td { class: "table-cell-label", "{ratio.name}" }
+                td { class: "table-cell-value text-right", "{ratio} ({ratio.range()})" }
+                if with_limits {
+                    td { class: "table-cell-value text-right", "-" }
+                }
+            }
+        };
+    }
+
+    let (marker, status) = match ratio.coverage() {
+        Some(Coverage::Deficit) => ("coverage-deficit", "Low"),
+        Some(Coverage::Excess) => ("coverage-excess", "High"),
+        Some(Coverage::Adequate) => ("coverage-adequate", "In range"),
+        None => ("", "-"),
+    };
+
+    rsx! {
+        tr { class: "table-row-alt",
+            td { class: "table-cell-label", "{ratio.name}" }
+            td { class: "table-cell-value text-right", "{ratio}" }
+            td { class: "table-cell-value text-right", {ratio.range()} }
+            if with_limits {
+                td { class: "table-cell-value text-right", "-" }
+            }
+            td { class: "table-cell-value text-right {marker}", "{status}" }
+        }
+    }
+}
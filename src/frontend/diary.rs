@@ -0,0 +1,178 @@
+use crate::backend::diary::{day_balances, week_balance, KEY_MINERALS};
+use crate::backend::nutrients::{Amount, Intake, NewRecommendedIntake};
+use crate::backend::profiles::{self, date_from_days};
+use crate::backend::recipe::Recipe;
+use crate::backend::storage;
+use crate::frontend::components::NutrientTable;
+use crate::frontend::recipe::RecipeBuilder;
+use crate::shared::types::{DiaryPeriod, Units};
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+/// Feeding diary page, what a saved dog ate per day compared against its
+/// recommended intake.
+#[component]
+pub fn FeedingDiary() -> Element {
+    let units = use_context::<Signal<Units>>();
+    let loaded = use_hook(profiles::load);
+    let mut saved = use_signal(|| loaded.clone().unwrap_or_default());
+    let mut error = use_signal(|| loaded.err().map(|load_error| load_error.to_string()));
+    let mut name = use_signal(|| {
+        saved
+            .peek()
+            .first()
+            .map(|profile| profile.name.clone())
+            .unwrap_or_default()
+    });
+    let mut date = use_signal(|| date_from_days(storage::today()));
+    let mut period = use_signal(DiaryPeriod::default);
+    let mut day = use_signal(Recipe::default);
+    // The dog and date `day` was loaded for, edits are only stored once loaded
+    let mut loaded_day: Signal<Option<(String, String)>> = use_signal(|| None);
+
+    let selected = use_memo(move || saved().into_iter().find(|profile| profile.name == name()));
+    let target = use_memo(move || {
+        selected()
+            .map(|profile| Intake::new_recommended_intake(&profile.inputs(storage::today())))
+            .unwrap_or_default()
+    });
+
+    use_effect(move || {
+        let (name, date) = (name(), date());
+        let recipe = saved
+            .peek()
+            .iter()
+            .find(|profile| profile.name == name)
+            .map(|profile| profile.diary_day(&date))
+            .unwrap_or_default();
+        day.set(recipe);
+        loaded_day.set(Some((name, date)));
+    });
+    use_effect(move || {
+        let recipe = day();
+        let Some((name, date)) = loaded_day.peek().clone() else {
+            return;
+        };
+        let mut profiles = saved.peek().clone();
+        let Some(profile) = profiles.iter_mut().find(|profile| profile.name == name) else {
+            return;
+        };
+        if profile.diary_day(&date) == recipe {
+            return;
+        }
+        profile.set_diary_day(&date, recipe);
+        error.set(profiles::save(&profiles).err().map(|save_error| save_error.to_string()));
+        saved.set(profiles);
+    });
+
+    rsx! {
+        div { class: "container w-[70%] mx-auto px-4 py-8",
+            ul { class: "w-[70%] mx-auto flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+                div { class: "calculator-item-header", "Dog and Date" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    div { class: "flex justify-center gap-2 text-center m-1",
+                        select {
+                            class: "number-input",
+                            id: "diaryProfileSelect",
+                            onchange: move |event| name.set(event.value()),
+                            {saved().into_iter().map(|profile| rsx! {
+                                option {
+                                    value: "{profile.name}",
+                                    selected: profile.name == name(),
+                                    "{profile.name}"
+                                }
+                            })}
+                        }
+                        input {
+                            r#type: "date",
+                            value: "{date}",
+                            class: "number-input",
+                            id: "diaryDateInput",
+                            oninput: move |event| date.set(event.value())
+                        }
+                    }
+                    if saved().is_empty() {
+                        div { class: "text-center m-1", "Save a dog profile in the calculator to start a diary" }
+                    }
+                    if let Some(error) = error() {
+                        div { class: "input-error", "{error}" }
+                    }
+                }
+                div { class: "calculator-item-header", "Compare" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    {DiaryPeriod::iter().map(|variant| rsx! {
+                        button {
+                            class: "selectable-button",
+                            aria_pressed: if period() == variant { false } else { true },
+                            onclick: move |_| period.set(variant),
+                            "{variant}"
+                        }
+                    })}
+                }
+            }
+
+            if let Some(profile) = selected() {
+                // What the dog ate on the selected date
+                RecipeBuilder { recipe: day }
+
+                div { class: "mt-4",
+                    match period() {
+                        DiaryPeriod::Daily => rsx! {
+                            h1 { class: "text-2xl font-bold text-center mb-6", "Eaten on {date}" }
+                            NutrientTable {
+                                kcal_label: "Daily Calories",
+                                kcal: target().daily_kcal,
+                                nutrients: target().nutrients,
+                                supplied: if day().is_empty() { None } else { Some(day().intake()) },
+                            }
+                        },
+                        DiaryPeriod::Weekly => rsx! {
+                            if let Some(week) = week_balance(&profile.diary, &target(), &date()) {
+                                h1 { class: "text-2xl font-bold text-center mb-6",
+                                    "Eaten in the week of {week.week_start} ({week.days} days logged)"
+                                }
+                                NutrientTable {
+                                    kcal_label: "Weekly Calories",
+                                    kcal: week.target.daily_kcal,
+                                    nutrients: week.target.nutrients,
+                                    supplied: if week.days == 0 { None } else { Some(week.supplied) },
+                                }
+                            }
+                        },
+                    }
+                }
+
+                // Running surplus (positive) or deficit (negative) over all logged days
+                div { class: "mt-4",
+                    h1 { class: "text-2xl font-bold text-center mb-6", "Cumulative Surplus and Deficit" }
+                    table { class: "w-[80%] mx-auto mb-6 bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
+                        thead { class: "bg-gray-100 dark:bg-gray-700",
+                            tr {
+                                th { class: "table-header", "Date" }
+                                th { class: "table-header text-right", "Calories" }
+                                th { class: "table-header text-right", "Difference" }
+                                th { class: "table-header text-right", "Cumulative" }
+                                {KEY_MINERALS.iter().map(|mineral| rsx! {
+                                    th { class: "table-header text-right", "{mineral}" }
+                                })}
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
+                            {day_balances(&profile.diary, &target()).into_iter().map(|balance| rsx! {
+                                tr { class: "table-row-alt",
+                                    td { class: "table-cell-label", "{balance.date}" }
+                                    td { class: "table-cell-value text-right", {balance.supplied.daily_kcal.display(units())} }
+                                    td { class: "table-cell-value text-right", {balance.difference.daily_kcal.display(units())} }
+                                    td { class: "table-cell-value text-right", {balance.cumulative.daily_kcal.display(units())} }
+                                    {balance.key_minerals().into_iter().map(|(_, amount)| rsx! {
+                                        td { class: "table-cell-value text-right", {amount.display(units())} }
+                                    })}
+                                }
+                            })}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::backend::ingredients;
+use crate::frontend::components::NutrientTable;
+use dioxus::prelude::*;
+
+/// Ingredients page, search the bundled dataset and show a nutrient profile.
+#[component]
+pub fn Ingredients() -> Element {
+    let mut query = use_signal(String::new);
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+
+    rsx! {
+        div { class: "container w-[70%] mx-auto px-4 py-8",
+            ul { class: "w-[70%] mx-auto flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+                div { class: "calculator-item-header", "Search Ingredients" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    input {
+                        r#type: "text",
+                        value: "{query}",
+                        placeholder: "e.g. liver",
+                        class: "number-input",
+                        id: "ingredientSearchInput",
+                        oninput: move |event| query.set(event.value())
+                    }
+                }
+                li { class: "z-30 flex-auto text-center m-1",
+                    {ingredients::search(&query()).into_iter().map(|ingredient| {
+                        let name = ingredient.name.clone();
+                        rsx! {
+                            button {
+                                class: "selectable-button m-1",
+                                aria_pressed: if selected().as_deref() == Some(name.as_str()) { false } else { true },
+                                onclick: move |_| selected.set(Some(name.clone())),
+                                "{ingredient.name}"
+                            }
+                        }
+                    })}
+                }
+            }
+
+            if let Some(ingredient) = selected().and_then(|name| ingredients::find(&name)) {
+                div { class: "mt-4",
+                    h1 { class: "text-2xl font-bold text-center mb-6", "{ingredient.name} (per 100 g)" }
+                    NutrientTable {
+                        kcal_label: "Calories",
+                        kcal: ingredient.kcal,
+                        nutrients: ingredient.nutrients.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod batch;
+pub mod chart;
+pub mod components;
+pub mod diary;
+pub mod ingredients;
+pub mod optimizer;
+pub mod plan;
+pub mod profiles;
+pub mod recipe;
@@ -0,0 +1,127 @@
+use crate::backend::calculator::{optimize_recipe, IngredientOption, Objective};
+use crate::backend::ingredients;
+use crate::backend::nutrients::{Intake, Nutrients};
+use crate::backend::recipe::Recipe;
+use dioxus::prelude::*;
+use strum::IntoEnumIterator;
+
+/// Pick the allowed ingredients with their inclusion limits and let the
+/// optimizer compute a recipe meeting the intake without exceeding the upper
+/// limits.
+#[component]
+pub fn RecipeOptimizer(
+    intake: ReadOnlySignal<Intake>,
+    upper_limits: ReadOnlySignal<Nutrients>,
+    recipe: Signal<Recipe>,
+) -> Element {
+    let mut options: Signal<Vec<IngredientOption>> = use_signal(Vec::new);
+    let mut ingredient = use_signal(|| {
+        ingredients::all()
+            .first()
+            .map(|ingredient| ingredient.name.clone())
+            .unwrap_or_default()
+    });
+    let mut objective = use_signal(Objective::default);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    rsx! {
+        ul { class: "w-[70%] mx-auto mt-4 flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+            div { class: "calculator-item-header", "Optimize Recipe" }
+            li { class: "z-30 flex-auto text-center m-1",
+                div { class: "flex justify-center gap-2 text-center m-1",
+                    select {
+                        class: "number-input",
+                        id: "optimizerIngredientSelect",
+                        onchange: move |event| ingredient.set(event.value()),
+                        {ingredients::all().iter().map(|option| rsx! {
+                            option {
+                                value: "{option.name}",
+                                selected: option.name == ingredient(),
+                                "{option.name}"
+                            }
+                        })}
+                    }
+                    button {
+                        class: "selectable-button",
+                        onclick: move |_| {
+                            options.write().push(IngredientOption {
+                                ingredient: ingredient(),
+                                min_grams: 0.0,
+                                max_grams: None,
+                                cost_per_kg: 0.0,
+                            })
+                        },
+                        "Allow"
+                    }
+                }
+            }
+            {options().into_iter().enumerate().map(|(index, option)| rsx! {
+                li { class: "w-full flex justify-center items-center gap-2 text-center m-1",
+                    span { class: "w-1/4 text-right", "{option.ingredient}" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        placeholder: "Min g",
+                        value: "{option.min_grams}",
+                        class: "number-input",
+                        oninput: move |event| {
+                            options.write()[index].min_grams = event.parsed::<f32>().unwrap_or(0.0)
+                        }
+                    }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        placeholder: "Max g",
+                        value: option.max_grams.map(|grams| grams.to_string()).unwrap_or_default(),
+                        class: "number-input",
+                        oninput: move |event| options.write()[index].max_grams = event.parsed::<f32>().ok()
+                    }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        placeholder: "Cost per kg",
+                        value: "{option.cost_per_kg}",
+                        class: "number-input",
+                        oninput: move |event| {
+                            options.write()[index].cost_per_kg = event.parsed::<f32>().unwrap_or(0.0)
+                        }
+                    }
+                    button {
+                        class: "selectable-button",
+                        onclick: move |_| {
+                            options.write().remove(index);
+                        },
+                        "Remove"
+                    }
+                }
+            })}
+            div { class: "calculator-item-header", "Minimize" }
+            li { class: "z-30 flex-auto text-center m-1",
+                {Objective::iter().map(|variant| rsx! {
+                    button {
+                        class: "selectable-button",
+                        aria_pressed: if objective() == variant { false } else { true },
+                        onclick: move |_| objective.set(variant),
+                        "{variant}"
+                    }
+                })}
+                button {
+                    class: "selectable-button ml-4",
+                    onclick: move |_| {
+                        match optimize_recipe(&intake(), &options(), Some(&upper_limits()), objective()) {
+                            Ok(optimized) => {
+                                recipe.set(optimized);
+                                error.set(None);
+                            }
+                            Err(solve_error) => error.set(Some(solve_error.to_string())),
+                        }
+                    },
+                    "Optimize"
+                }
+            }
+            if let Some(message) = error() {
+                div { class: "w-full text-center coverage-deficit", "{message}" }
+            }
+        }
+    }
+}
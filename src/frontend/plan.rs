@@ -0,0 +1,127 @@
+use crate::backend::nutrients::{Amount, FromValue, Kilogram};
+use crate::backend::plan::weight_plan;
+use crate::frontend::components::NutrientTable;
+use crate::shared::types::{Inputs, UnitSystem, Units};
+use dioxus::prelude::*;
+
+/// Weight plan page, a week by week weight loss or gain plan towards a target
+/// weight for the dog entered in the calculator.
+#[component]
+pub fn WeightPlanner() -> Element {
+    let units = use_context::<Signal<Units>>();
+    let calculator_inputs = use_context::<Signal<Inputs>>();
+    // Weights as entered, in the selected unit system (kg or lb)
+    let mut current_input = use_signal(|| match calculator_inputs.peek().weight {
+        0.0 => String::new(),
+        weight => format!("{:.1}", Kilogram::from_value(weight).get(units.peek().system)),
+    });
+    let mut target_input = use_signal(String::new);
+    let mut rate_percentage: Signal<f32> = use_signal(|| 1.0);
+
+    let current = use_memo(move || Kilogram::parse(&current_input(), units().system));
+    let target = use_memo(move || Kilogram::parse(&target_input(), units().system));
+    let plan = use_memo(move || {
+        let (Ok(current), Ok(target)) = (current(), target()) else {
+            return None;
+        };
+        // Only the weight differs from the calculator inputs
+        let inputs = Inputs {
+            weight: current.get(UnitSystem::Metric),
+            ..calculator_inputs()
+        };
+        Some(weight_plan(&inputs, target, rate_percentage()))
+    });
+    let unit = match units().system {
+        UnitSystem::Metric => "kg",
+        UnitSystem::Imperial => "lb",
+    };
+
+    rsx! {
+        div { class: "container w-[70%] mx-auto px-4 py-8",
+            ul { class: "w-[70%] mx-auto flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+                div { class: "calculator-item-header",
+                    "Current and Target Weight ({unit}) and Rate (% per week)"
+                }
+                li { class: "z-30 flex-auto text-center m-1",
+                    div { class: "flex justify-center gap-2 text-center m-1",
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "0.1",
+                            placeholder: "Current weight",
+                            value: "{current_input}",
+                            class: "number-input",
+                            id: "planCurrentWeightInput",
+                            oninput: move |event| current_input.set(event.value())
+                        }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "0.1",
+                            placeholder: "Target weight",
+                            value: "{target_input}",
+                            class: "number-input",
+                            id: "planTargetWeightInput",
+                            oninput: move |event| target_input.set(event.value())
+                        }
+                        input {
+                            r#type: "number",
+                            min: "0.1",
+                            max: "2",
+                            step: "0.1",
+                            value: "{rate_percentage}",
+                            class: "number-input",
+                            id: "planRateInput",
+                            oninput: move |event| rate_percentage.set(event.parsed::<f32>().unwrap_or(0.0))
+                        }
+                    }
+                    // Empty inputs are not an error yet
+                    if let (Err(error), false) = (current(), current_input().trim().is_empty()) {
+                        div { class: "input-error", "Current weight: {error}" }
+                    }
+                    if let (Err(error), false) = (target(), target_input().trim().is_empty()) {
+                        div { class: "input-error", "Target weight: {error}" }
+                    }
+                    if let Some(Err(error)) = plan() {
+                        div { class: "input-error", "{error}" }
+                    }
+                    div { class: "text-center text-sm m-1",
+                        "Age, breed, activity level, neuter status and guideline standard are taken from the calculator"
+                    }
+                }
+            }
+
+            if let Some(Ok(plan)) = plan() {
+                div { class: "mt-4",
+                    h1 { class: "text-2xl font-bold text-center mb-6",
+                        "Weight Plan ({plan.weeks.len()} weeks)"
+                    }
+                    table { class: "w-[80%] mx-auto mb-6 bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
+                        thead { class: "bg-gray-100 dark:bg-gray-700",
+                            tr {
+                                th { class: "table-header", "Week" }
+                                th { class: "table-header text-right", "Target Weight" }
+                                th { class: "table-header text-right", "Daily Calories" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
+                            {plan.weeks.iter().map(|week| rsx! {
+                                tr { class: "table-row-alt",
+                                    td { class: "table-cell-label", "{week.week}" }
+                                    td { class: "table-cell-value text-right", {week.target_weight.display(units())} }
+                                    td { class: "table-cell-value text-right", {week.daily_kcal.display(units())} }
+                                }
+                            })}
+                        }
+                    }
+                    h1 { class: "text-2xl font-bold text-center mb-6", "Daily Requirements at the Target Weight" }
+                    NutrientTable {
+                        kcal_label: "Daily Calories",
+                        kcal: plan.target_intake.daily_kcal,
+                        nutrients: plan.target_intake.nutrients,
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::backend::nutrients::{Amount, Kcal, Kilogram};
+use crate::backend::profiles::{self, date_from_days, Profile, WeightEntry};
+use crate::backend::storage;
+use crate::frontend::chart::WeightChart;
+use dioxus::prelude::*;
+
+/// Switch between saved dog profiles and save the calculator inputs under a
+/// name. `current` holds the calculator inputs, its name and birthdate are
+/// taken from the switcher. Saved profiles also keep a weight log, weighings
+/// are logged with the current weight and daily energy.
+#[component]
+pub fn ProfileSwitcher(
+    current: ReadOnlySignal<Profile>,
+    daily_kcal: ReadOnlySignal<Kcal>,
+    ideal_band: ReadOnlySignal<(Kilogram, Kilogram)>,
+    on_load: EventHandler<Profile>,
+) -> Element {
+    let loaded = use_hook(profiles::load);
+    let mut saved = use_signal(|| loaded.clone().unwrap_or_default());
+    let mut error = use_signal(|| loaded.err().map(|load_error| load_error.to_string()));
+    let mut name = use_signal(String::new);
+    let mut birthdate = use_signal(String::new);
+    let mut weighing_date = use_signal(|| date_from_days(storage::today()));
+    let selected = use_memo(move || saved().into_iter().find(|profile| profile.name == name()));
+
+    let mut store = move |profiles: Vec<Profile>| {
+        error.set(profiles::save(&profiles).err().map(|save_error| save_error.to_string()));
+        saved.set(profiles);
+    };
+
+    rsx! {
+        ul { class: "w-[70%] mx-auto mb-4 flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+            div { class: "calculator-item-header", "Dog Profile" }
+            li { class: "z-30 flex-auto text-center m-1",
+                div { class: "flex justify-center gap-2 text-center m-1",
+                    select {
+                        class: "number-input",
+                        id: "profileSelect",
+                        onchange: move |event| {
+                            let selected = saved().into_iter().find(|profile| profile.name == event.value());
+                            if let Some(profile) = selected {
+                                name.set(profile.name.clone());
+                                birthdate.set(profile.birthdate.clone());
+                                on_load.call(profile);
+                            }
+                        },
+                        option { value: "", "New dog" }
+                        {saved().into_iter().map(|profile| rsx! {
+                            option {
+                                value: "{profile.name}",
+                                selected: profile.name == name(),
+                                "{profile.name}"
+                            }
+                        })}
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: "Name",
+                        value: "{name}",
+                        class: "number-input",
+                        id: "profileNameInput",
+                        oninput: move |event| name.set(event.value())
+                    }
+                    input {
+                        r#type: "date",
+                        value: "{birthdate}",
+                        class: "number-input",
+                        id: "profileBirthdateInput",
+                        oninput: move |event| birthdate.set(event.value())
+                    }
+                    button {
+                        class: "selectable-button",
+                        disabled: name().trim().is_empty(),
+                        onclick: move |_| {
+                            let mut profile = Profile {
+                                name: name().trim().to_string(),
+                                birthdate: birthdate(),
+                                ..current()
+                            };
+                            // Saving under an existing name updates that profile, keeping its history
+                            let mut profiles = saved();
+                            match profiles.iter_mut().find(|saved| saved.name == profile.name) {
+                                Some(saved) => {
+                                    profile.weight_log = std::mem::take(&mut saved.weight_log);
+                                    profile.diary = std::mem::take(&mut saved.diary);
+                                    *saved = profile;
+                                }
+                                None => profiles.push(profile),
+                            }
+                            store(profiles);
+                        },
+                        "Save"
+                    }
+                    button {
+                        class: "selectable-button",
+                        disabled: selected().is_none(),
+                        onclick: move |_| {
+                            let mut profiles = saved();
+                            profiles.retain(|profile| profile.name != name());
+                            store(profiles);
+                            name.set(String::new());
+                            birthdate.set(String::new());
+                        },
+                        "Delete"
+                    }
+                }
+                if let Some(error) = error() {
+                    div { class: "input-error", "{error}" }
+                }
+            }
+
+            // Weight log of the selected profile
+            if let Some(profile) = selected() {
+                div { class: "calculator-item-header", "Weight Log of {profile.name}" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    div { class: "flex justify-center gap-2 text-center m-1",
+                        input {
+                            r#type: "date",
+                            value: "{weighing_date}",
+                            class: "number-input",
+                            id: "weighingDateInput",
+                            oninput: move |event| weighing_date.set(event.value())
+                        }
+                        button {
+                            class: "selectable-button",
+                            disabled: current().weight <= 0.0,
+                            onclick: move |_| {
+                                let mut profiles = saved();
+                                if let Some(profile) = profiles.iter_mut().find(|profile| profile.name == name()) {
+                                    profile.log_weight(WeightEntry {
+                                        date: weighing_date(),
+                                        weight: current().weight,
+                                        daily_kcal: daily_kcal().value(),
+                                    });
+                                }
+                                store(profiles);
+                            },
+                            "Log Current Weight"
+                        }
+                    }
+                    WeightChart { entries: profile.weight_log, ideal_band: ideal_band() }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::backend::ingredients;
+use crate::backend::nutrients::{Amount, FromValue, Gram};
+use crate::backend::recipe::{self, Recipe, RecipeItem, SavedRecipe};
+use crate::shared::types::Units;
+use dioxus::prelude::*;
+
+/// Compose a daily recipe from the ingredient dataset with gram amounts. With
+/// `meals` (the fraction of the day of every meal) the grams per meal are
+/// listed next to every ingredient. Recipes can be saved under a name and
+/// loaded again.
+#[component]
+pub fn RecipeBuilder(recipe: Signal<Recipe>, meals: Option<Vec<f32>>) -> Element {
+    let units = use_context::<Signal<Units>>();
+    let meals = meals.filter(|meals| meals.len() > 1).unwrap_or_default();
+    let loaded = use_hook(recipe::load_saved);
+    let mut saved = use_signal(|| loaded.clone().unwrap_or_default());
+    let mut error = use_signal(|| loaded.err().map(|load_error| load_error.to_string()));
+    let mut name = use_signal(String::new);
+    let mut ingredient = use_signal(|| {
+        ingredients::all()
+            .first()
+            .map(|ingredient| ingredient.name.clone())
+            .unwrap_or_default()
+    });
+    let mut grams: Signal<f32> = use_signal(|| 100.0);
+
+    rsx! {
+        ul { class: "w-[70%] mx-auto mt-4 flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
+            div { class: "calculator-item-header", "Recipe ({Gram::from_value(recipe().total_grams()).display(units())} per day)" }
+            li { class: "z-30 flex-auto text-center m-1",
+                div { class: "flex justify-center gap-2 text-center m-1",
+                    select {
+                        class: "number-input",
+                        id: "recipeIngredientSelect",
+                        onchange: move |event| ingredient.set(event.value()),
+                        {ingredients::all().iter().map(|option| rsx! {
+                            option {
+                                value: "{option.name}",
+                                selected: option.name == ingredient(),
+                                "{option.name}"
+                            }
+                        })}
+                    }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        value: "{grams}",
+                        class: "number-input",
+                        id: "recipeGramsInput",
+                        oninput: move |event| grams.set(event.parsed::<f32>().unwrap_or(0.0))
+                    }
+                    // Amounts are entered in grams in both unit systems
+                    span { class: "text-sm", "g" }
+                    button {
+                        class: "selectable-button",
+                        onclick: move |_| {
+                            recipe.write().items.push(RecipeItem {
+                                ingredient: ingredient(),
+                                grams: grams(),
+                            })
+                        },
+                        "Add"
+                    }
+                }
+            }
+            // Saved recipes, saving under an existing name replaces that recipe
+            li { class: "z-30 flex-auto text-center m-1",
+                div { class: "flex justify-center gap-2 text-center m-1",
+                    select {
+                        class: "number-input",
+                        id: "savedRecipeSelect",
+                        onchange: move |event| {
+                            if let Some(selected) = saved().into_iter().find(|saved| saved.name == event.value()) {
+                                name.set(selected.name);
+                                recipe.set(selected.recipe);
+                            }
+                        },
+                        option { value: "", "Saved recipes" }
+                        {saved().into_iter().map(|option| rsx! {
+                            option {
+                                value: "{option.name}",
+                                selected: option.name == name(),
+                                "{option.name}"
+                            }
+                        })}
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: "Recipe name",
+                        value: "{name}",
+                        class: "number-input",
+                        id: "recipeNameInput",
+                        oninput: move |event| name.set(event.value())
+                    }
+                    button {
+                        class: "selectable-button",
+                        disabled: name().trim().is_empty() || recipe().is_empty(),
+                        onclick: move |_| {
+                            let mut recipes = saved();
+                            recipes.retain(|saved| saved.name != name().trim());
+                            recipes.push(SavedRecipe {
+                                name: name().trim().to_string(),
+                                recipe: recipe(),
+                            });
+                            error.set(recipe::save_saved(&recipes).err().map(|save_error| save_error.to_string()));
+                            saved.set(recipes);
+                        },
+                        "Save Recipe"
+                    }
+                }
+                if let Some(error) = error() {
+                    div { class: "input-error", "{error}" }
+                }
+            }
+            {recipe().items.into_iter().enumerate().map(|(index, item)| rsx! {
+                li { class: "w-full flex justify-center items-center gap-2 text-center m-1",
+                    span { class: "w-1/3 text-right", "{item.ingredient}" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        value: "{item.grams}",
+                        class: "number-input",
+                        oninput: move |event| {
+                            recipe.write().items[index].grams = event.parsed::<f32>().unwrap_or(0.0)
+                        }
+                    }
+                    span { class: "text-sm", "g" }
+                    if !meals.is_empty() {
+                        span { class: "text-sm",
+                            {meals.iter().map(|fraction| Gram::from_value(item.grams * fraction).display(units())).collect::<Vec<_>>().join(" / ")}
+                            " per meal"
+                        }
+                    }
+                    button {
+                        class: "selectable-button",
+                        onclick: move |_| {
+                            recipe.write().items.remove(index);
+                        },
+                        "Remove"
+                    }
+                }
+            })}
+        }
+    }
+}
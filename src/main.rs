@@ -1,9 +1,28 @@
 mod backend;
+mod frontend;
 mod shared;
 
-use backend::nutrients::{FromValue, Intake, Kilogram, MetabolicBodyWeight, NewRecommendedIntake};
+use backend::breeds::{self, Breed, WEEKS_PER_YEAR};
+use backend::nutrients::{
+    ideal_weight, ideal_weight_band, Amount, FromValue, Intake, Kilogram, NewRecommendedIntake, Nutrients,
+};
+use backend::meals::{default_meals, MealSplit, MAX_MEALS_PER_DAY};
+use backend::profiles::Profile;
+use backend::recipe::Recipe;
+use backend::{requirements, storage};
 use dioxus::prelude::*;
-use shared::types::{ActivityLevel, Age};
+use frontend::batch::MealPrep;
+use frontend::components::NutrientTable;
+use frontend::diary::FeedingDiary;
+use frontend::ingredients::Ingredients;
+use frontend::optimizer::RecipeOptimizer;
+use frontend::plan::WeightPlanner;
+use frontend::profiles::ProfileSwitcher;
+use frontend::recipe::RecipeBuilder;
+use shared::types::{
+    ActivityLevel, Age, EnergyUnit, GuidelineStandard, Inputs, NeuterStatus, ReproductiveStatus,
+    UnitSystem, Units,
+};
 use strum::IntoEnumIterator;
 
 #[derive(Debug, Clone, Routable, PartialEq)]
@@ -14,18 +33,33 @@ enum Route {
     Home {},
     #[route("/calculator")]
     Calculator {},
+    #[route("/ingredients")]
+    Ingredients {},
+    #[route("/plan")]
+    WeightPlanner {},
+    #[route("/diary")]
+    FeedingDiary {},
+    #[route("/batch")]
+    MealPrep {},
 }
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/main.css");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
 
+/// The smallest weight the weight input steps down to, one input step.
+const MIN_WEIGHT: f32 = 0.1;
+
 fn main() {
     dioxus::launch(App);
 }
 
 #[component]
 fn App() -> Element {
+    use_context_provider(|| Signal::new(Units::default()));
+    // The dog entered in the calculator, the weight planner plans for it
+    use_context_provider(|| Signal::new(Inputs::default()));
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
@@ -54,6 +88,10 @@ fn Home() -> Element {
 pub fn Calculator() -> Element {
     rsx! {
         div { id: "calculator",
+            // Custom requirement tables that failed to load are replaced by the bundled ones
+            if let Some(error) = requirements::override_error() {
+                div { class: "input-error", "{error}, using the bundled requirement tables" }
+            }
 
             // Content
             EnumInputComponent {}
@@ -63,24 +101,198 @@ pub fn Calculator() -> Element {
 
 #[component]
 fn EnumInputComponent() -> Element {
-    let mut age = use_signal(|| Age::default());
-    let mut activity_level = use_signal(|| ActivityLevel::default());
-    let mut weight_kg: Signal<u32> = use_signal(|| 0);
-    let mut intake = use_signal(|| Intake::default());
+    let mut age = use_signal(Age::default);
+    let mut activity_level = use_signal(ActivityLevel::default);
+    let mut breed: Signal<Option<&'static Breed>> = use_signal(|| None);
+    let mut standard = use_signal(GuidelineStandard::default);
+    let mut neuter_status = use_signal(NeuterStatus::default);
+    let mut body_condition_score: Signal<u32> = use_signal(|| 5);
+    let mut units = use_context::<Signal<Units>>();
+    // Weights as entered, in the selected unit system (kg or lb)
+    let mut weight_input = use_signal(String::new);
+    let mut age_weeks: Signal<Option<u32>> = use_signal(|| None);
+    let mut age_years: Signal<Option<u32>> = use_signal(|| None);
+    // With an age in years the age group follows the senior age of the selected breed
+    let mut classify_age = move || {
+        if let Some(years) = age_years() {
+            let size_class = breed().map(|breed| breed.size_class).unwrap_or_default();
+            age.set(size_class.age(years * WEEKS_PER_YEAR));
+        }
+    };
+    let mut adult_weight_input = use_signal(String::new);
+    let mut reproductive_status = use_signal(ReproductiveStatus::default);
+    let mut litter_size: Signal<u32> = use_signal(|| 4);
+    let mut lactation_week: Signal<u32> = use_signal(|| 1);
+    let mut intake = use_signal(Intake::default);
+    let mut upper_limits = use_signal(Nutrients::default);
+    let recipe = use_signal(Recipe::default);
+    // `None` uses the usual number of meals for the age
+    let mut meals_per_day: Signal<Option<u32>> = use_signal(|| None);
+    let mut meal_shares = use_signal(String::new);
+    let meal_split = use_memo(move || {
+        MealSplit::new(meals_per_day().unwrap_or(default_meals(age())), &meal_shares())
+    });
+    let meals = use_memo(move || meal_split().ok().map(|split| split.fractions().to_vec()));
+
+    // An empty input is not an error, the dog simply has no weight yet
+    let weight = use_memo(move || match weight_input().trim() {
+        "" => Ok(Kilogram::default()),
+        input => Kilogram::parse(input, units().system),
+    });
+    let adult_weight = use_memo(move || match adult_weight_input().trim() {
+        "" => Ok(None),
+        input => Kilogram::parse(input, units().system).map(Some),
+    });
+
+    // `None` while a weight is invalid
+    let inputs = use_memo(move || {
+        let (Ok(weight), Ok(adult_weight)) = (weight(), adult_weight()) else {
+            return None;
+        };
+        Some(Inputs {
+            age: age(),
+            weight: weight.get(UnitSystem::Metric),
+            activity_level: activity_level(),
+            breed_energy_factor: breed().map(|breed| breed.energy_factor),
+            neuter_status: neuter_status(),
+            body_condition_score: Some(body_condition_score()),
+            standard: standard(),
+            age_weeks: age_weeks(),
+            expected_adult_weight: adult_weight.map(|adult_weight| adult_weight.get(UnitSystem::Metric)),
+            reproductive_status: reproductive_status(),
+            litter_size: litter_size(),
+            lactation_week: lactation_week(),
+        })
+    });
+
+    let mut calculator_inputs = use_context::<Signal<Inputs>>();
+    use_effect(move || {
+        if let Some(inputs) = inputs() {
+            calculator_inputs.set(inputs);
+        }
+    });
+
+    // The calculator inputs that are saved with a dog profile
+    let profile = use_memo(move || Profile {
+        weight: weight().map_or(0.0, |weight| weight.get(UnitSystem::Metric)),
+        breed: breed().map(|breed| breed.name.clone()),
+        activity_level: activity_level(),
+        neuter_status: neuter_status(),
+        ..Default::default()
+    });
+    let daily_kcal = use_memo(move || intake().daily_kcal);
+    let ideal_band = use_memo(move || inputs().map(|inputs| ideal_weight_band(&inputs)).unwrap_or_default());
+    let load_profile = move |profile: Profile| {
+        weight_input.set(match profile.weight {
+            0.0 => String::new(),
+            weight => format_weight(Kilogram::from_value(weight).get(units().system)),
+        });
+        let loaded_breed = profile.breed.as_deref().and_then(breeds::find);
+        if let Some(loaded_breed) = loaded_breed {
+            adult_weight_input.set(format_weight(loaded_breed.expected_adult_weight().get(units().system)));
+        }
+        breed.set(loaded_breed);
+        activity_level.set(profile.activity_level);
+        neuter_status.set(profile.neuter_status);
+        // Without a birthdate the age stays as selected
+        let today = storage::today();
+        if let Some(profile_age) = profile.age(today) {
+            age.set(profile_age);
+            age_weeks.set(profile.age_weeks(today));
+            age_years.set(profile.age_weeks(today).map(|weeks| weeks / WEEKS_PER_YEAR));
+        }
+    };
 
     use_effect(move || {
-        let metabolic_bw = MetabolicBodyWeight::new(Kilogram::from_value(weight_kg() as f32));
-        intake.set(Intake::new_recommended_intake(
-            &metabolic_bw,
-            activity_level(),
-        ));
+        // Keep showing the last valid intake while a weight is invalid
+        let Some(inputs) = inputs() else {
+            return;
+        };
+        let recommended = Intake::new_recommended_intake(&inputs);
+        upper_limits.set(recommended.upper_limits(&inputs));
+        intake.set(recommended);
     });
 
     rsx! {
         div { class: "container w-[70%] mx-auto px-4 py-8",
+            ProfileSwitcher {
+                current: profile,
+                daily_kcal: daily_kcal,
+                ideal_band: ideal_band,
+                on_load: load_profile,
+            }
+
             // Calculator inputs section
             ul { class: "w-[70%] mx-auto flex flex-wrap p-2 rounded-md bg-gray-200 dark:bg-gray-800 shadow-md",
-                // Age and Activity Level sections use the same pattern
+                // Unit preferences, switching converts the entered weight
+                    div { class: "calculator-item-header", "Select Units" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        {UnitSystem::iter().map(|variant| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if units().system == variant { false } else { true },
+                                onclick: move |_| {
+                                    if let (Ok(weight), false) = (weight(), weight_input().trim().is_empty()) {
+                                        weight_input.set(format_weight(weight.get(variant)));
+                                    }
+                                    if let Ok(Some(adult_weight)) = adult_weight() {
+                                        adult_weight_input.set(format_weight(adult_weight.get(variant)));
+                                    }
+                                    units.write().system = variant;
+                                },
+                                "{variant}"
+                            }
+                        })}
+                        {EnergyUnit::iter().map(|variant| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if units().energy == variant { false } else { true },
+                                onclick: move |_| units.write().energy = variant,
+                                "{variant}"
+                            }
+                        })}
+                    }
+                // Guideline standard, Age and Activity Level sections use the same pattern
+                    div { class: "calculator-item-header", "Select Guideline Standard" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        {GuidelineStandard::iter().map(|variant| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if standard() == variant { false } else { true },
+                                onclick: move |_| standard.set(variant),
+                                "{variant}"
+                            }
+                        })}
+                    }
+                // Breed, prefills the expected adult weight and adjusts the energy requirement
+                    div { class: "calculator-item-header", "Select Breed" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        select {
+                            class: "number-input",
+                            id: "breedSelect",
+                            onchange: move |event| {
+                                let selected = breeds::find(&event.value());
+                                if let Some(selected) = selected {
+                                    adult_weight_input.set(format_weight(selected.expected_adult_weight().get(units().system)));
+                                }
+                                breed.set(selected);
+                                classify_age();
+                            },
+                            option { value: "", "Mixed / Unknown" }
+                            {breeds::all().iter().map(|option| rsx! {
+                                option {
+                                    value: "{option.name}",
+                                    selected: breed().is_some_and(|breed| breed.name == option.name),
+                                    "{option.name}"
+                                }
+                            })}
+                        }
+                        if let Some(breed) = breed() {
+                            div { class: "text-center m-1",
+                                "{breed.size_class}, typically {Kilogram::from_value(breed.min_weight).display(units())} - {Kilogram::from_value(breed.max_weight).display(units())}"
+                            }
+                        }
+                    }
                     div { class: "calculator-item-header", "Select Age" }
                     li { class: "z-30 flex-auto text-center m-1",
                         {Age::iter().map(|variant| rsx! {
@@ -91,6 +303,24 @@ fn EnumInputComponent() -> Element {
                                 "{variant}"
                             }
                         })}
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            placeholder: "Age in years",
+                            value: age_years().map(|years| years.to_string()).unwrap_or_default(),
+                            class: "number-input",
+                            id: "ageYearsInput",
+                            oninput: move |event| {
+                                age_years.set(event.parsed::<u32>().ok());
+                                classify_age();
+                            }
+                        }
+                        // Larger breeds age faster
+                        if let Some(breed) = breed() {
+                            div { class: "text-center m-1",
+                                "Senior from {breed.size_class.senior_age_years()} years for the {breed.name}"
+                            }
+                        }
                     }
                     div { class: "calculator-item-header", "Select Activity Level" }
                     li { class: "z-30 flex-auto text-center m-1",
@@ -104,8 +334,121 @@ fn EnumInputComponent() -> Element {
                         })}
                     }
 
+                // Neutering only lowers the energy needs of grown dogs
+                if age() != Age::Puppy {
+                    div { class: "calculator-item-header", "Select Neuter Status" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        {NeuterStatus::iter().map(|variant| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if neuter_status() == variant { false } else { true },
+                                onclick: move |_| neuter_status.set(variant),
+                                "{variant}"
+                            }
+                        })}
+                    }
+                }
+
+                // Body condition score, requirements are based on the ideal weight it implies
+                    div { class: "calculator-item-header", "Body Condition Score (1-9)" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        {(1..=9).map(|score| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if body_condition_score() == score { false } else { true },
+                                onclick: move |_| body_condition_score.set(score),
+                                "{score}"
+                            }
+                        })}
+                        if let Some(inputs) = inputs().filter(|inputs| inputs.weight > 0.0) {
+                            div { class: "text-center m-1",
+                                "Estimated ideal weight: {ideal_weight(&inputs).display(units())}"
+                            }
+                        }
+                    }
+
+                // Puppy specific inputs, growth depends on age and expected adult weight
+                if age() == Age::Puppy {
+                    div { class: "calculator-item-header",
+                        match units().system {
+                            UnitSystem::Metric => "Puppy Age (weeks) and Expected Adult Weight (kg)",
+                            UnitSystem::Imperial => "Puppy Age (weeks) and Expected Adult Weight (lb)",
+                        }
+                    }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        div { class: "flex justify-center gap-2 text-center m-1",
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                placeholder: "Age in weeks",
+                                value: age_weeks().map(|weeks| weeks.to_string()).unwrap_or_default(),
+                                class: "number-input",
+                                id: "puppyAgeInput",
+                                oninput: move |event| age_weeks.set(event.parsed::<u32>().ok())
+                            }
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                step: "0.1",
+                                placeholder: "Expected adult weight",
+                                value: "{adult_weight_input}",
+                                class: "number-input",
+                                id: "expectedAdultWeightInput",
+                                oninput: move |event| adult_weight_input.set(event.value())
+                            }
+                        }
+                        if let Err(error) = adult_weight() {
+                            div { class: "input-error", "{error}" }
+                        }
+                    }
+                }
+
+                // Reproductive status, not applicable to puppies
+                if age() != Age::Puppy {
+                    div { class: "calculator-item-header", "Select Reproductive Status" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        {ReproductiveStatus::iter().map(|variant| rsx! {
+                            button {
+                                class: "selectable-button",
+                                aria_pressed: if reproductive_status() == variant { false } else { true },
+                                onclick: move |_| reproductive_status.set(variant),
+                                "{variant}"
+                            }
+                        })}
+                    }
+                }
+                if age() != Age::Puppy && reproductive_status() == ReproductiveStatus::Lactation {
+                    div { class: "calculator-item-header", "Litter Size and Week of Lactation" }
+                    li { class: "z-30 flex-auto text-center m-1",
+                        div { class: "flex justify-center gap-2 text-center m-1",
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                value: "{litter_size()}",
+                                class: "number-input",
+                                id: "litterSizeInput",
+                                oninput: move |event| litter_size.set(event.parsed::<u32>().unwrap_or(0))
+                            }
+                            input {
+                                r#type: "number",
+                                min: "1",
+                                max: "4",
+                                value: "{lactation_week()}",
+                                class: "number-input",
+                                id: "lactationWeekInput",
+                                oninput: move |event| lactation_week.set(event.parsed::<u32>().unwrap_or(1))
+                            }
+                        }
+                    }
+                }
+
                 // Weight input section
-                div { class: "calculator-item-header", "Select Weight (kg)" }
+                div { class: "calculator-item-header",
+                    match units().system {
+                        UnitSystem::Metric => "Select Weight (kg)",
+                        UnitSystem::Imperial => "Select Weight (lb)",
+                    }
+                }
                 li { class: "z-30 flex-auto text-center m-1",
                     div { class: "flex justify-center text-center m-1",
                         div { class: "relative",
@@ -113,124 +456,91 @@ fn EnumInputComponent() -> Element {
                                 r#type: "button",
                                 class: "absolute right-9 top-1 rounded bg-slate-800 p-1.5 border border-transparent text-center text-sm text-white transition-all shadow-sm hover:shadow focus:bg-slate-700 focus:shadow-none active:bg-slate-700 hover:bg-slate-700 active:shadow-none disabled:pointer-events-none disabled:opacity-50 disabled:shadow-none",
                                 id: "weightDecreaseButton",
-                                onclick: move |_| weight_kg.set(weight_kg().saturating_sub(1)),
+                                onclick: move |_| {
+                                    let current = weight().map(|weight| weight.get(units().system)).unwrap_or(0.0);
+                                    weight_input.set(format_weight((current - 1.0).max(MIN_WEIGHT)));
+                                },
                                 MinusIcon {}
                             }
                             input {
                                 r#type: "number",
-                                value: "{weight_kg()}",
+                                value: "{weight_input}",
                                 min: "0",
+                                step: "0.1",
                                 class: "w-min-0 bg-transparent placeholder:text-slate-400 text-white text-sm border border-slate-200 rounded-md pl-3 pr-20 py-2 transition duration-300 ease focus:outline-none focus:border-slate-400 hover:border-slate-300 shadow-sm focus:shadow appearance-none [&::-webkit-outer-spin-button]:appearance-none [&::-webkit-inner-spin-button]:appearance-none",
                                 id: "weightInput",
-                                oninput: move |event| weight_kg.set(event.parsed::<u32>().unwrap_or(0))
+                                oninput: move |event| weight_input.set(event.value())
                             }
                             button {
                                 r#type: "button",
                                 class: "absolute right-1 top-1 rounded bg-slate-800 p-1.5 border border-transparent text-center text-sm text-white transition-all shadow-sm hover:shadow focus:bg-slate-700 focus:shadow-none active:bg-slate-700 hover:bg-slate-700 active:shadow-none disabled:pointer-events-none disabled:opacity-50 disabled:shadow-none",
                                 id: "weightIncreaseButton",
-                                onclick: move |_| weight_kg.set(weight_kg().saturating_add(1)),
+                                onclick: move |_| {
+                                    let current = weight().map(|weight| weight.get(units().system)).unwrap_or(0.0);
+                                    weight_input.set(format_weight(current + 1.0));
+                                },
                                 PlusIcon {}
                             }
                         }
                     }
-                }
-            }
-
-            // Results table section
-            div { class: "mt-4",
-                h1 { class: "text-2xl font-bold text-center mb-6", "Nutrient Intake" }
-                table { class: "w-[80%] mx-auto bg-white dark:bg-gray-800 shadow-lg rounded-lg overflow-hidden",
-                    thead { class: "bg-gray-100 dark:bg-gray-700",
-                        tr {
-                            th { class: "table-header w-1/2", "Category" }
-                            th { class: "table-header w-1/2 text-right", "Value" }
-                        }
+                    if let Err(error) = weight() {
+                        div { class: "input-error", "{error}" }
                     }
-                    tbody { class: "divide-y divide-gray-200 dark:divide-gray-700",
-                        // Main nutrients section
-                        tr { class: "table-row-alt",
-                            td { class: "table-cell-label w-1/2", "Daily Calories" }
-                            td { class: "table-cell-value w-1/2 text-right", "{intake().daily_kcal}" }
-                        }
-                        tr { class: "table-row-alt",
-                            td { class: "table-cell-label w-1/2", "Protein" }
-                            td { class: "table-cell-value w-1/2 text-right", "{intake().nutrients.protein}" }
-                        }
-                        tr { class: "table-row-alt",
-                            td { class: "table-cell-label w-1/2", "Fat" }
-                            td { class: "table-cell-value w-1/2 text-right", "{intake().nutrients.fat}" }
-                        }
-
-                        // Amino Acids section
-                        tr {
-                            td { class: "table-section-header", colspan: "2", "Amino Acids" }
-                        }
-                        {
-                            let current_intake = intake();
-                            rsx!({
-                                (&current_intake.nutrients.amino_acids).into_iter().map(|(name, value)| rsx!(
-                                    tr { class: "table-row-alt",
-                                        td { class: "table-cell-label w-1/2", "{name}" }
-                                        td { class: "table-cell-value w-1/2 text-right", "{value}" }
-                                    }
-                                ))
-                            })
-                        }
+                }
 
-                        // Fatty Acids section
-                        tr {
-                            td { class: "table-section-header", colspan: "2", "Fatty Acids" }
+                // Meals, the recipe and results can be shown per meal
+                div { class: "calculator-item-header", "Meals per Day and Split (e.g. 40/60)" }
+                li { class: "z-30 flex-auto text-center m-1",
+                    div { class: "flex justify-center gap-2 text-center m-1",
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "{MAX_MEALS_PER_DAY}",
+                            value: "{meals_per_day().unwrap_or(default_meals(age()))}",
+                            class: "number-input",
+                            id: "mealsPerDayInput",
+                            oninput: move |event| meals_per_day.set(Some(event.parsed::<u32>().unwrap_or(0)))
                         }
-                        {
-                            let current_intake = intake();
-                            rsx!({
-                                (&current_intake.nutrients.fatty_acids).into_iter().map(|(name, value)| rsx!(
-                                    tr { 
-                                        td { class: "table-cell-label w-1/2", "{name}" }
-                                        td { class: "table-cell-value w-1/2 text-right", "{value}" }
-                                    }
-                                ))
-                            })
+                        input {
+                            r#type: "text",
+                            placeholder: "Equal",
+                            value: "{meal_shares}",
+                            class: "number-input",
+                            id: "mealSplitInput",
+                            oninput: move |event| meal_shares.set(event.value())
                         }
+                    }
+                    if let Err(error) = meal_split() {
+                        div { class: "input-error", "{error}" }
+                    }
+                }
+            }
 
-                        // Minerals section
-                        tr {
-                            td { class: "table-section-header", colspan: "2", "Minerals" }
-                        }
-                        {
-                            let current_intake = intake();
-                            rsx!({
-                                (&current_intake.nutrients.minerals).into_iter().map(|(name, value)| rsx!(
-                                    tr {class: "table-row-alt",
-                                        td { class: "table-cell-label w-1/2", "{name}" }
-                                        td { class: "table-cell-value w-1/2 text-right", "{value}" }
-                                    }
-                                ))
-                            })
-                        }
+            // Recipe section, compared against the intake in the results table
+            RecipeBuilder { recipe, meals: meals() }
+            RecipeOptimizer { intake, upper_limits, recipe }
 
-                        // Vitamins section
-                        tr {
-                            td { class: "table-section-header", colspan: "2", "Vitamins" }
-                        }
-                        {
-                            let current_intake = intake();
-                            rsx!({
-                                (current_intake.nutrients.vitamins).into_iter().map(|(name, value)| rsx!(
-                                    tr { class: "table-row-alt",
-                                        td { class: "table-cell-label w-1/2", "{name}" }
-                                        td { class: "table-cell-value w-1/2 text-right", "{value}" }
-                                    }
-                                ))
-                            })
-                        }
-                    }
+            // Results table section
+            div { class: "mt-4",
+                h1 { class: "text-2xl font-bold text-center mb-6", "Nutrient Intake" }
+                NutrientTable {
+                    kcal_label: "Daily Calories",
+                    kcal: intake().daily_kcal,
+                    nutrients: intake().nutrients,
+                    supplied: if recipe().is_empty() { None } else { Some(recipe().intake()) },
+                    upper_limits: Some(upper_limits()),
+                    meals: meals(),
                 }
             }
         }
     }
 }
 
+/// Weights are shown with at most one decimal.
+fn format_weight(value: f32) -> String {
+    ((value * 10.0).round() / 10.0).to_string()
+}
+
 /// Shared navbar component.
 #[component]
 fn Navbar() -> Element {
@@ -238,12 +548,17 @@ fn Navbar() -> Element {
         div { id: "navbar",
             Link { to: Route::Home {}, "Home" }
             Link { to: Route::Calculator {}, "Nutrient Calculator" }
+            Link { to: Route::Ingredients {}, "Ingredients" }
+            Link { to: Route::WeightPlanner {}, "Weight Plan" }
+            Link { to: Route::FeedingDiary {}, "Feeding Diary" }
+            Link { to: Route::MealPrep {}, "Meal Prep" }
         }
 
         Outlet::<Route> {}
     }
 }
 
+#[component]
 fn PlusIcon() -> Element {
     rsx!(
         svg { view_box: "0 0 16 16", fill: "currentColor", class: "w-4 h-4",
@@ -252,6 +567,7 @@ fn PlusIcon() -> Element {
     )
 }
 
+#[component]
 fn MinusIcon() -> Element {
     rsx!(
         svg { view_box: "0 0 16 16", fill: "currentColor", class: "w-4 h-4",
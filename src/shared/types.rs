@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use strum::EnumIter;
 
@@ -15,25 +16,166 @@ impl fmt::Display for Age {
     }
 }
 
-#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter, Serialize, Deserialize)]
 pub enum ActivityLevel {
     Sedentary,
     #[default]
     Moderate,
     Active,
     High,
+    /// Racing sled dogs in extreme cold, which need several times the energy
+    /// of any other working dog.
     Extreme,
 }
 
 impl fmt::Display for ActivityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActivityLevel::Extreme => write!(f, "Sled Racing in Extreme Cold"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum ReproductiveStatus {
+    #[default]
+    Maintenance,
+    /// Last 4-5 weeks of gestation, before that a bitch eats as an adult.
+    Gestation,
+    Lactation,
+}
+
+impl fmt::Display for ReproductiveStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
-#[derive(Clone, Default, Copy)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter, Serialize, Deserialize)]
+pub enum NeuterStatus {
+    #[default]
+    Intact,
+    Neutered,
+}
+
+impl fmt::Display for NeuterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum EnergyUnit {
+    #[default]
+    Kcal,
+    Kilojoule,
+}
+
+impl fmt::Display for EnergyUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnergyUnit::Kcal => write!(f, "kcal"),
+            EnergyUnit::Kilojoule => write!(f, "kJ"),
+        }
+    }
+}
+
+/// The published nutrient guidelines the requirements are taken from.
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum GuidelineStandard {
+    /// NRC 2006 recommended allowances.
+    Nrc,
+    /// AAFCO dog food nutrient profiles, adult maintenance and growth.
+    Aafco,
+    #[default]
+    Fediaf,
+}
+
+impl fmt::Display for GuidelineStandard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuidelineStandard::Nrc => write!(f, "NRC 2006"),
+            GuidelineStandard::Aafco => write!(f, "AAFCO"),
+            GuidelineStandard::Fediaf => write!(f, "FEDIAF"),
+        }
+    }
+}
+
+/// What the amounts of the results table are expressed per.
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum NutrientBasis {
+    #[default]
+    Daily,
+    PerThousandKcal,
+    PerKgDryMatter,
+}
+
+impl fmt::Display for NutrientBasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NutrientBasis::Daily => write!(f, "Per Day"),
+            NutrientBasis::PerThousandKcal => write!(f, "Per 1000 kcal"),
+            NutrientBasis::PerKgDryMatter => write!(f, "Per kg DM"),
+        }
+    }
+}
+
+/// Period of the feeding diary comparison.
+#[derive(Debug, Default, PartialEq, Clone, Copy, EnumIter)]
+pub enum DiaryPeriod {
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl fmt::Display for DiaryPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The units weights and results are shown in.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Units {
+    pub system: UnitSystem,
+    pub energy: EnergyUnit,
+}
+
+#[derive(Clone, Default, Copy, PartialEq)]
 pub struct Inputs {
     pub age: Age,
+    /// Current body weight in kg.
     pub weight: f32,
     pub activity_level: ActivityLevel,
+    /// Energy factor of the breed on top of the activity level, `None` for
+    /// mixed or unknown breeds.
+    pub breed_energy_factor: Option<f32>,
+    pub neuter_status: NeuterStatus,
+    /// Body condition score on the 9-point scale, `None` when not assessed
+    /// which is treated as the ideal score of 5.
+    pub body_condition_score: Option<u32>,
+    pub standard: GuidelineStandard,
+    /// Only used for puppies.
+    pub age_weeks: Option<u32>,
+    /// Only used for puppies, in kg.
+    pub expected_adult_weight: Option<f32>,
+    pub reproductive_status: ReproductiveStatus,
+    /// Only used during lactation.
+    pub litter_size: u32,
+    /// Only used during lactation, starting at week 1.
+    pub lactation_week: u32,
 }